@@ -0,0 +1,113 @@
+//! Compares `pluck!` lambdas against the equivalent hand-written closures.
+//!
+//! Each pair should report the same time, since `pluck!` expands to the same
+//! closure body. To check the generated code directly, compare the assembly of
+//! the `#[inline(never)]` functions below, for example with
+//! [`cargo-show-asm`](https://crates.io/crates/cargo-show-asm):
+//!
+//! ```text
+//! cargo asm --bench access access::tuple_pluck
+//! cargo asm --bench access access::tuple_closure
+//! ```
+//!
+//! The same goes for `named_pluck` and `named_closure`, which time a
+//! [`pluck_fn!`] function against a closure. The benchmarks only compare
+//! timings and do not check the assembly themselves.
+//!
+//! When the bodies are identical, the compiler may merge the pair into a single
+//! function, so only one of the symbols is emitted.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pluck::*;
+
+const LEN: u64 = 1024;
+
+fn tuples() -> Vec<(u64, u64)> {
+    (0..LEN).map(|i| (i, i * 2)).collect()
+}
+
+fn nested() -> Vec<Vec<u64>> {
+    (0..LEN).map(|i| vec![i, i + 1, i + 2]).collect()
+}
+
+fn numbers() -> Vec<u64> {
+    (0..LEN).collect()
+}
+
+#[inline(never)]
+pub fn tuple_pluck(list: &[(u64, u64)]) -> u64 {
+    list.iter().map(pluck!(.1)).sum()
+}
+
+#[inline(never)]
+pub fn tuple_closure(list: &[(u64, u64)]) -> u64 {
+    list.iter().map(|value| value.1).sum()
+}
+
+#[inline(never)]
+pub fn index_pluck(list: &[Vec<u64>]) -> u64 {
+    list.iter().map(pluck!([2])).sum()
+}
+
+#[inline(never)]
+pub fn index_closure(list: &[Vec<u64>]) -> u64 {
+    list.iter().map(|value| value[2]).sum()
+}
+
+#[inline(never)]
+pub fn deref_pluck(list: &[&u64]) -> u64 {
+    list.iter().map(pluck!(**)).sum()
+}
+
+#[inline(never)]
+pub fn deref_closure(list: &[&u64]) -> u64 {
+    list.iter().map(|value| **value).sum()
+}
+
+pluck_fn!(fn second(&(u64, u64)) -> u64 = .1);
+
+#[inline(never)]
+pub fn named_pluck(list: &[(u64, u64)]) -> u64 {
+    list.iter().map(second).sum()
+}
+
+#[inline(never)]
+pub fn named_closure(list: &[(u64, u64)]) -> u64 {
+    list.iter().map(|value| value.1).sum()
+}
+
+fn tuple(c: &mut Criterion) {
+    let list = tuples();
+    let mut group = c.benchmark_group("tuple");
+    group.bench_function("pluck", |b| b.iter(|| tuple_pluck(black_box(&list))));
+    group.bench_function("closure", |b| b.iter(|| tuple_closure(black_box(&list))));
+    group.finish();
+}
+
+fn index(c: &mut Criterion) {
+    let list = nested();
+    let mut group = c.benchmark_group("index");
+    group.bench_function("pluck", |b| b.iter(|| index_pluck(black_box(&list))));
+    group.bench_function("closure", |b| b.iter(|| index_closure(black_box(&list))));
+    group.finish();
+}
+
+fn deref(c: &mut Criterion) {
+    let numbers = numbers();
+    let list = numbers.iter().collect::<Vec<_>>();
+    let mut group = c.benchmark_group("deref");
+    group.bench_function("pluck", |b| b.iter(|| deref_pluck(black_box(&list))));
+    group.bench_function("closure", |b| b.iter(|| deref_closure(black_box(&list))));
+    group.finish();
+}
+
+fn named(c: &mut Criterion) {
+    let list = tuples();
+    let mut group = c.benchmark_group("named");
+    group.bench_function("pluck_fn", |b| b.iter(|| named_pluck(black_box(&list))));
+    group.bench_function("closure", |b| b.iter(|| named_closure(black_box(&list))));
+    group.finish();
+}
+
+criterion_group!(benches, tuple, index, deref, named);
+criterion_main!(benches);
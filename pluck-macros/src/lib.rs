@@ -0,0 +1,333 @@
+//! Procedural implementation of [`pluck!`](https://docs.rs/pluck), enabled by
+//! the `proc-macro` feature of `pluck` and used as `pluck::parsed::pluck!`.
+//!
+//! Unlike the `macro_rules!` version, this parses its input into a syntax
+//! tree, so chained tuple indices like `.0.1` and arbitrary expressions in
+//! method arguments and indices are handled the same way as in Rust itself.
+//! In exchange, it accepts a restricted grammar:
+//!
+//! ```text
+//! pluck   := `move`? access (`,` access)* `,`?
+//! access  := prefix* segment*
+//! prefix  := `&` | `&mut` | `*` | `!` | `clone` | `copy`
+//! segment := `.` field | `.` index | `.` method turbofish? `(` args `)`
+//!          | `[` expr `]` | `?`
+//! ```
+//!
+//! The generated lambdas refer to helpers through the `::pluck` path, so the
+//! `pluck` crate must not be renamed.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    AngleBracketedGenericArguments, Error, Expr, Ident, Index, LitFloat, LitInt, Member, Token,
+};
+
+/// Create a lambda that extracts the provided property from the argument.
+#[proc_macro]
+pub fn pluck(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match syn::parse::<Pluck>(input) {
+        Ok(pluck) => pluck.into_token_stream().into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+struct Pluck {
+    move_token: Option<Token![move]>,
+    accesses: Punctuated<Access, Token![,]>,
+}
+
+impl Parse for Pluck {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let move_token: Option<Token![move]> = input.parse()?;
+        if input.is_empty() {
+            let message = if move_token.is_some() {
+                "expected a property to pluck after `move`"
+            } else {
+                "expected a property to pluck"
+            };
+            return Err(error(input, message));
+        }
+
+        let accesses = Punctuated::parse_terminated(input)?;
+        Ok(Pluck { move_token, accesses })
+    }
+}
+
+impl ToTokens for Pluck {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let var = Ident::new("value", Span::mixed_site());
+        let mut body = if self.accesses.len() == 1 && !self.accesses.trailing_punct() {
+            self.accesses[0].expand(&var)
+        } else {
+            let elems = self.accesses.iter().map(|access| access.expand(&var));
+            quote!((#(#elems,)*))
+        };
+
+        if self.accesses.iter().any(Access::is_try) {
+            body = quote!(::core::option::Option::Some(#body));
+        }
+
+        let move_token = &self.move_token;
+        tokens.extend(quote!(#move_token |#var| #body));
+    }
+}
+
+struct Access {
+    prefixes: Vec<Prefix>,
+    segments: Vec<Segment>,
+}
+
+enum Prefix {
+    Ref,
+    RefMut,
+    Deref,
+    Not,
+    Clone,
+    Copy,
+}
+
+enum Segment {
+    Field(Member),
+    Method {
+        name: Ident,
+        turbofish: Option<AngleBracketedGenericArguments>,
+        args: Punctuated<Expr, Token![,]>,
+    },
+    Index(Expr),
+    Try,
+}
+
+impl Access {
+    fn is_try(&self) -> bool {
+        self.segments.iter().any(|segment| matches!(segment, Segment::Try))
+    }
+
+    fn expand(&self, var: &Ident) -> TokenStream {
+        let mut expr = quote!(#var);
+        for segment in &self.segments {
+            expr = match segment {
+                Segment::Field(member) => quote!(#expr.#member),
+                Segment::Method { name, turbofish, args } => quote!(#expr.#name #turbofish (#args)),
+                Segment::Index(index) => quote!(#expr[#index]),
+                Segment::Try => quote!((*#expr.as_ref()?)),
+            };
+        }
+
+        for prefix in self.prefixes.iter().rev() {
+            expr = match prefix {
+                Prefix::Ref => quote!(&#expr),
+                Prefix::RefMut => quote!(&mut #expr),
+                Prefix::Deref => quote!(*#expr),
+                Prefix::Not => quote!(::pluck::__private::not(#expr)),
+                Prefix::Clone => quote!(::core::clone::Clone::clone(&#expr)),
+                Prefix::Copy => quote!(::pluck::__private::copy(&#expr)),
+            };
+        }
+        expr
+    }
+}
+
+impl Parse for Access {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![,]) {
+            return Err(error(input, "expected a property before `,`"));
+        }
+
+        let mut prefixes = Vec::new();
+        let mut last = None;
+        loop {
+            if input.peek(Token![&&]) {
+                input.parse::<Token![&&]>()?;
+                prefixes.extend([Prefix::Ref, Prefix::Ref]);
+                last = Some("&");
+            } else if input.peek(Token![&]) {
+                input.parse::<Token![&]>()?;
+                if input.parse::<Option<Token![mut]>>()?.is_some() {
+                    prefixes.push(Prefix::RefMut);
+                    last = Some("&mut");
+                } else {
+                    prefixes.push(Prefix::Ref);
+                    last = Some("&");
+                }
+            } else if input.peek(Token![*]) {
+                input.parse::<Token![*]>()?;
+                prefixes.push(Prefix::Deref);
+                last = None;
+            } else if input.peek(Token![!]) {
+                input.parse::<Token![!]>()?;
+                prefixes.push(Prefix::Not);
+                last = Some("!");
+            } else if input.peek(Ident) {
+                let ident = input.parse::<Ident>()?;
+                if ident == "clone" {
+                    prefixes.push(Prefix::Clone);
+                    last = Some("clone");
+                } else if ident == "copy" {
+                    prefixes.push(Prefix::Copy);
+                    last = Some("copy");
+                } else {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("expected a field, index, method call, or deref, found `{}`", ident),
+                    ));
+                }
+            } else {
+                break;
+            }
+        }
+
+        let mut segments = Vec::new();
+        loop {
+            if input.peek(Token![.]) {
+                input.parse::<Token![.]>()?;
+                parse_dotted(input, &mut segments)?;
+            } else if input.peek(syn::token::Bracket) {
+                let content;
+                syn::bracketed!(content in input);
+                if content.is_empty() {
+                    return Err(error(&content, "expected an index inside `[]`"));
+                }
+                segments.push(Segment::Index(content.parse()?));
+            } else if input.peek(Token![?]) {
+                input.parse::<Token![?]>()?;
+                segments.push(Segment::Try);
+            } else {
+                break;
+            }
+        }
+
+        if !input.is_empty() && !input.peek(Token![,]) {
+            return Err(error(input, "expected a field, index, method call, or deref"));
+        }
+        if segments.is_empty() {
+            if let Some(last) = last {
+                return Err(error(input, format!("expected a property after `{}`", last)));
+            }
+        }
+
+        Ok(Access { prefixes, segments })
+    }
+}
+
+/// Like [`ParseStream::error`], without the "unexpected end of input" prefix
+/// when nothing is left to parse.
+fn error<T: std::fmt::Display>(input: ParseStream, message: T) -> Error {
+    if input.is_empty() {
+        Error::new(Span::call_site(), message)
+    } else {
+        input.error(message)
+    }
+}
+
+fn parse_dotted(input: ParseStream, segments: &mut Vec<Segment>) -> syn::Result<()> {
+    if input.peek(LitInt) {
+        let lit = input.parse::<LitInt>()?;
+        segments.push(Segment::Field(Member::Unnamed(Index {
+            index: lit.base10_parse()?,
+            span: lit.span(),
+        })));
+    } else if input.peek(LitFloat) {
+        // `.0.1` lexes as `.` followed by the float `0.1`.
+        let lit = input.parse::<LitFloat>()?;
+        for part in lit.to_string().split('.') {
+            let index = part.parse().map_err(|_| {
+                Error::new(lit.span(), format!("expected a tuple index, found `{}`", lit))
+            })?;
+            segments.push(Segment::Field(Member::Unnamed(Index { index, span: lit.span() })));
+        }
+    } else if input.peek(Ident) {
+        let name = input.parse::<Ident>()?;
+        let turbofish = if input.peek(Token![::]) {
+            Some(AngleBracketedGenericArguments::parse_turbofish(input)?)
+        } else {
+            None
+        };
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let args = Punctuated::parse_terminated(&content)?;
+            segments.push(Segment::Method { name, turbofish, args });
+        } else if turbofish.is_some() {
+            return Err(error(input, "expected arguments after the turbofish"));
+        } else {
+            segments.push(Segment::Field(Member::Named(name)));
+        }
+    } else {
+        return Err(error(input, "expected a field, index, or method call after `.`"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(input: &str) -> String {
+        match syn::parse_str::<Pluck>(input) {
+            Ok(pluck) => pluck.into_token_stream().to_string(),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    fn expected(output: TokenStream) -> String {
+        output.to_string()
+    }
+
+    #[test]
+    fn field() {
+        assert_eq!(expand(".name"), expected(quote!(|value| value.name)));
+    }
+
+    #[test]
+    fn chained_tuple_indices() {
+        assert_eq!(expand(".0.1.2"), "| value | value . 0 . 1 . 2");
+    }
+
+    #[test]
+    fn method_arguments_are_expressions() {
+        assert_eq!(
+            expand(".max(1.5, f(a, b))"),
+            expected(quote!(|value| value.max(1.5, f(a, b)))),
+        );
+        assert_eq!(
+            expand(".parse::<i32>()"),
+            expected(quote!(|value| value.parse::<i32>())),
+        );
+    }
+
+    #[test]
+    fn prefixes() {
+        assert_eq!(expand("&mut .0"), expected(quote!(|value| &mut value.0)));
+        assert_eq!(
+            expand("clone .name"),
+            expected(quote!(|value| ::core::clone::Clone::clone(&value.name))),
+        );
+    }
+
+    #[test]
+    fn tuple() {
+        assert_eq!(expand(".a, &.b"), expected(quote!(|value| (value.a, &value.b,))));
+        assert_eq!(expand(".a,"), expected(quote!(|value| (value.a,))));
+    }
+
+    #[test]
+    fn optional_chain() {
+        assert_eq!(
+            expand(".parent?.name"),
+            expected(quote!(|value| ::core::option::Option::Some((*value.parent.as_ref()?).name))),
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(expand(""), "expected a property to pluck");
+        assert_eq!(expand("&"), "expected a property after `&`");
+        assert_eq!(expand(".a,,.b"), "expected a property before `,`");
+        assert_eq!(expand("."), "expected a field, index, or method call after `.`");
+        assert_eq!(expand(".a[]"), "expected an index inside `[]`");
+    }
+}
@@ -0,0 +1,133 @@
+use core::ops::Index;
+
+/// Extract the value at `Idx` without a macro.
+///
+/// Implemented for tuples of up to six elements using the [`Idx0`] through
+/// [`Idx5`] markers, and for references to any type implementing [`Index`].
+///
+/// ```
+/// # use pluck::*;
+/// assert_eq!((1, "a").pluck(Idx1), "a");
+/// assert_eq!(Pluck::<Idx0>::pluck((1, "a"), Idx0), 1);
+///
+/// let list = vec![[0, 1], [2, 3]];
+/// let second = list.iter().map(|row| row.pluck(1)).collect::<Vec<_>>();
+/// assert_eq!(second, &[&1, &3]);
+/// ```
+pub trait Pluck<Idx> {
+    /// The plucked value.
+    type Output;
+
+    /// Extract the value at `idx`.
+    fn pluck(self, idx: Idx) -> Self::Output;
+}
+
+impl<'a, T, Idx> Pluck<Idx> for &'a T
+where
+    T: Index<Idx> + ?Sized,
+    T::Output: 'a,
+{
+    type Output = &'a T::Output;
+
+    #[inline]
+    fn pluck(self, idx: Idx) -> Self::Output {
+        &self[idx]
+    }
+}
+
+macro_rules! markers {
+    ($($(#[$attr:meta])* $marker:ident)+) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            pub struct $marker;
+        )+
+    };
+}
+
+markers! {
+    /// Marker selecting the first element of a tuple.
+    Idx0
+    /// Marker selecting the second element of a tuple.
+    Idx1
+    /// Marker selecting the third element of a tuple.
+    Idx2
+    /// Marker selecting the fourth element of a tuple.
+    Idx3
+    /// Marker selecting the fifth element of a tuple.
+    Idx4
+    /// Marker selecting the sixth element of a tuple.
+    Idx5
+}
+
+macro_rules! tuple {
+    ([$($ty:ident)+] $($marker:ident $index:tt $output:ident),+) => {
+        tuple!(@impl [$($ty)+] $($marker $index $output)+);
+    };
+    (@impl [$($ty:ident)+] $marker:ident $index:tt $output:ident $($rest:tt)*) => {
+        impl<$($ty),+> Pluck<$marker> for ($($ty,)+) {
+            type Output = $output;
+
+            #[inline]
+            fn pluck(self, _: $marker) -> Self::Output {
+                self.$index
+            }
+        }
+
+        tuple!(@impl [$($ty)+] $($rest)*);
+    };
+    (@impl [$($ty:ident)+]) => {};
+}
+
+tuple!([A B] Idx0 0 A, Idx1 1 B);
+tuple!([A B C] Idx0 0 A, Idx1 1 B, Idx2 2 C);
+tuple!([A B C D] Idx0 0 A, Idx1 1 B, Idx2 2 C, Idx3 3 D);
+tuple!([A B C D E] Idx0 0 A, Idx1 1 B, Idx2 2 C, Idx3 3 D, Idx4 4 E);
+tuple!([A B C D E F] Idx0 0 A, Idx1 1 B, Idx2 2 C, Idx3 3 D, Idx4 4 E, Idx5 5 F);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pair() {
+        assert_eq!((1, "a").pluck(Idx0), 1);
+        assert_eq!((1, "a").pluck(Idx1), "a");
+    }
+
+    #[test]
+    fn six() {
+        let tuple = (0u8, 1u16, 2u32, 3u64, 4usize, "five");
+        assert_eq!(tuple.pluck(Idx0), 0);
+        assert_eq!(tuple.pluck(Idx3), 3);
+        assert_eq!(tuple.pluck(Idx5), "five");
+    }
+
+    #[test]
+    fn moves_out_of_tuple() {
+        let tuple = (String::from("a"), vec![1]);
+        assert_eq!(tuple.pluck(Idx1), vec![1]);
+    }
+
+    #[test]
+    fn turbofish() {
+        let list = vec![(1, 'a'), (2, 'b')];
+        let chars = list.into_iter().map(|t| Pluck::<Idx1>::pluck(t, Idx1)).collect::<Vec<_>>();
+        assert_eq!(chars, &['a', 'b']);
+    }
+
+    #[test]
+    fn index_type() {
+        let list = vec![10, 20, 30];
+        assert_eq!(list.pluck(1), &20);
+        assert_eq!((&list[..]).pluck(1..), &[20, 30]);
+    }
+
+    #[test]
+    fn map_index() {
+        use std::collections::HashMap;
+
+        let map = HashMap::from([("a", 1)]);
+        assert_eq!(map.pluck("a"), &1);
+    }
+}
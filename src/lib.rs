@@ -20,6 +20,17 @@
 //! assert_eq!(second, &["a", "b", "c"]);
 //! ```
 //!
+//! Any index the tuple has is supported, including multi-digit ones like
+//! `.12`. Indices can be chained to reach into nested tuples.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [((0, "a"), 'x'), ((1, "b"), 'y')];
+//!
+//! let nested = list.iter().map(pluck!(.0.1)).collect::<Vec<_>>();
+//! assert_eq!(nested, &["a", "b"]);
+//! ```
+//!
 //! ## Struct Properties
 //!
 //! Provide the property name to extract.
@@ -46,6 +57,50 @@
 //! assert_eq!(first, &[&0, &1, &2]);
 //! ```
 //!
+//! Properties that are already references, like `&str`, are [`Copy`] and can
+//! be plucked without `&`, which would add a second reference. For owned
+//! properties like [`String`], `&*` derefs before borrowing, plucking a
+//! `&str` instead of a `&String`.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str, nickname: String }
+//! let list = [Person { name: "Robert", nickname: "Bob".to_string() }];
+//!
+//! let names = list.iter().map(pluck!(.name)).collect::<Vec<&str>>();
+//! let name_refs = list.iter().map(pluck!(&.name)).collect::<Vec<&&str>>();
+//! let nicknames = list.iter().map(pluck!(&*.nickname)).collect::<Vec<&str>>();
+//! assert_eq!((names[0], *name_refs[0], nicknames[0]), ("Robert", "Robert", "Bob"));
+//! ```
+//!
+//! References can be stacked, like `&&.0`. Only the innermost reference
+//! borrows from the argument, so the outer ones borrow temporaries and cannot
+//! be returned from the lambda. They are useful inside [`cmp_by!`] or when the
+//! property already holds a reference.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [(&0, "a"), (&1, "b")];
+//!
+//! let first = list.iter().map(pluck!(&.0)).collect::<Vec<&&i32>>();
+//! assert_eq!(first, &[&&0, &&1]);
+//! ```
+//!
+//! Precede the property with `ref` to pluck exactly one reference either
+//! way: a copy of the property when it is already a reference, and a borrow
+//! of it otherwise. Only the outermost reference is checked, and the type
+//! must be known where the lambda is created, not a generic parameter.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str, age: u32 }
+//! let list = [Person { name: "Alice", age: 30 }];
+//!
+//! let names = list.iter().map(pluck!(ref .name)).collect::<Vec<&str>>();
+//! let ages = list.iter().map(pluck!(ref .age)).collect::<Vec<&u32>>();
+//! assert_eq!((names, ages), (vec!["Alice"], vec![&30]));
+//! ```
+//!
 //! ## Mutable Reference
 //!
 //! Precede the property name with `&mut` to pluck by mutable reference.
@@ -61,10 +116,341 @@
 //! assert_eq!(list, [(1, "a"), (2, "b"), (3, "c")]);
 //! ```
 //!
+//! Follow a mutably borrowed [`Option`] property with `or_default` to pluck a
+//! mutable reference to its contents, first inserting the
+//! [`Default`] value if it is [`None`].
+//!
+//! ```
+//! # use pluck::*;
+//! let mut list = [(Some(1),), (None,)];
+//!
+//! list.iter_mut().map(pluck!(&mut .0 or_default)).for_each(|num| *num += 1);
+//! assert_eq!(list, [(Some(2),), (Some(1),)]);
+//! ```
+//!
+//! ## Clone
+//!
+//! Precede the property name with `clone` to pluck a clone of the value.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: String }
+//! let list = [Person { name: "Alice".to_string() }];
+//!
+//! let names = list.iter().map(pluck!(clone .name)).collect::<Vec<String>>();
+//! assert_eq!(names, &["Alice"]);
+//! ```
+//!
+//! ## Copy
+//!
+//! Precede the property name with `copy` to pluck a copy of the value. Unlike
+//! `clone`, this only accepts [`Copy`] types, so it never runs a user-defined
+//! [`Clone`] implementation.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [(0, "a"), (1, "b"), (2, "c")];
+//!
+//! let first = list.iter().map(pluck!(copy .0)).collect::<Vec<i32>>();
+//! assert_eq!(first, &[0, 1, 2]);
+//! ```
+//!
+//! ## Moving and Borrowing
+//!
+//! Without a prefix, a property is moved out of the argument. When the
+//! argument is owned, as with [`into_iter`](IntoIterator::into_iter), any
+//! property can be moved out. When it is a reference, as with
+//! [`iter`](slice::iter), only [`Copy`] properties can be, so use `&`,
+//! `clone` or `copy` for the rest.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = vec![("a".to_string(), 0), ("b".to_string(), 1)];
+//!
+//! let borrowed = list.iter().map(pluck!(&.0)).collect::<Vec<&String>>();
+//! let cloned = list.iter().map(pluck!(clone .0)).collect::<Vec<String>>();
+//! let copied = list.iter().map(pluck!(.1)).collect::<Vec<i32>>();
+//! assert_eq!(borrowed, &["a", "b"]);
+//! assert_eq!(cloned, &["a", "b"]);
+//! assert_eq!(copied, &[0, 1]);
+//!
+//! let moved = list.into_iter().map(pluck!(.0)).collect::<Vec<String>>();
+//! assert_eq!(moved, &["a", "b"]);
+//! ```
+//!
+//! Method calls, `as` and `into` consume their receiver the same way, while
+//! `!` and arithmetic require [`Copy`] operands from borrowed arguments.
+//!
+//! ## Negation
+//!
+//! Precede a [`bool`] property with `!` to pluck its negation.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Task { name: &'static str, done: bool }
+//! let list = [Task { name: "a", done: true }, Task { name: "b", done: false }];
+//!
+//! let todo = list.iter().filter(pluck!(!.done)).map(pluck!(.name)).collect::<Vec<_>>();
+//! assert_eq!(todo, &["b"]);
+//! ```
+//!
+//! [`bool`] properties are plucked by value, so they can be used directly as
+//! predicates for [`retain`](Vec::retain), [`filter`](Iterator::filter) and
+//! [`partition`](Iterator::partition). `copy .done` is equivalent and makes
+//! the copy explicit.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Task { done: bool }
+//! let mut list = vec![Task { done: true }, Task { done: false }];
+//!
+//! list.retain(pluck!(.done));
+//! assert_eq!(list.len(), 1);
+//! ```
+//!
+//! ## Conversion
+//!
+//! Follow the property name with `into` and a type to convert the plucked
+//! value with [`Into`].
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [(0u8, "a"), (1u8, "b")];
+//!
+//! let wide = list.iter().map(pluck!(.0 into u64)).collect::<Vec<_>>();
+//! assert_eq!(wide, &[0u64, 1u64]);
+//!
+//! let owned = list.iter().map(pluck!(.1 into String)).collect::<Vec<_>>();
+//! assert_eq!(owned, &["a".to_string(), "b".to_string()]);
+//! ```
+//!
+//! Follow it with `as` and a type to cast the plucked value instead.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [(1, "a"), (2, "b")];
+//!
+//! let floats = list.iter().map(pluck!(.0 as f64)).collect::<Vec<_>>();
+//! assert_eq!(floats, &[1.0, 2.0]);
+//! ```
+//!
+//! ## Optional Chaining
+//!
+//! Follow an [`Option`] property with `?` to continue plucking from its
+//! contents. The lambda then returns an [`Option`], which is [`None`] if any
+//! link in the chain is [`None`]. A `?` inside method arguments or a closure
+//! belongs to that expression instead, and leaves the lambda's return type
+//! alone.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str, parent: Option<Box<Person>> }
+//! let list = [
+//!     Person { name: "Bob", parent: Some(Box::new(Person { name: "Alice", parent: None })) },
+//!     Person { name: "Alice", parent: None },
+//! ];
+//!
+//! let parents = list.iter().map(pluck!(.parent?.name)).collect::<Vec<_>>();
+//! assert_eq!(parents, &[Some("Alice"), None]);
+//! ```
+//!
+//! [`try_pluck!`] does the same for [`Result`] properties, returning a
+//! [`Result`] instead. As with the `?` operator, the error is converted with
+//! [`From`].
+//!
+//! ```
+//! # use pluck::*;
+//! struct Line { parsed: Result<(u32, char), String> }
+//! let list = [
+//!     Line { parsed: Ok((1, 'a')) },
+//!     Line { parsed: Err("bad line".to_string()) },
+//! ];
+//!
+//! let numbers = list.iter().map(try_pluck!(.parsed?.0)).collect::<Vec<Result<_, &String>>>();
+//! assert_eq!(numbers, &[Ok(1), Err(&"bad line".to_string())]);
+//! ```
+//!
+//! Start with `?` when the argument itself is an [`Option`] or [`Result`] to
+//! pluck from its contents, mapping over it by reference.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str }
+//! let list = [Some(Person { name: "Bob" }), None];
+//!
+//! let names = list.iter().map(pluck!(?.name)).collect::<Vec<_>>();
+//! assert_eq!(names, &[Some("Bob"), None]);
+//! ```
+//!
+//! ## Flatten
+//!
+//! Precede an [`Option`] property with `flatten` to borrow its contents as an
+//! `Option<&T>`, which can be passed straight to
+//! [`filter_map`](Iterator::filter_map).
+//!
+//! ```
+//! # use pluck::*;
+//! struct Record { maybe: Option<String> }
+//! let list = [Record { maybe: Some("a".to_string()) }, Record { maybe: None }];
+//!
+//! let present = list.iter().filter_map(pluck!(flatten .maybe)).collect::<Vec<_>>();
+//! assert_eq!(present, &["a"]);
+//! ```
+//!
+//! Repeat `flatten` for nested [`Option`]s.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [(Some(Some(1)),), (Some(None),), (None,)];
+//!
+//! let inner = list.iter().map(pluck!(flatten flatten .0)).collect::<Vec<_>>();
+//! assert_eq!(inner, &[Some(&1), None, None]);
+//! ```
+//!
+//! Precede a [`Result`] property with `ok` or `err` to borrow its success or
+//! error value as an `Option<&T>` in the same way.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Parsed { result: Result<i32, String> }
+//! let list = [Parsed { result: Ok(1) }, Parsed { result: Err("x".to_string()) }];
+//!
+//! let values = list.iter().filter_map(pluck!(ok .result)).collect::<Vec<_>>();
+//! assert_eq!(values, &[&1]);
+//!
+//! let errors = list.iter().filter_map(pluck!(err .result)).collect::<Vec<_>>();
+//! assert_eq!(errors, &["x"]);
+//! ```
+//!
+//! `copied` and `cloned` turn an `Option<&T>` argument or property into an
+//! `Option<T>` with [`Option::copied`] and [`Option::cloned`].
+//!
+//! ```
+//! # use pluck::*;
+//! let names = ["a".to_string()];
+//! let list = [(Some(&1), names.first()), (None, None)];
+//!
+//! let nums = list.iter().map(pluck!(copied .0)).collect::<Vec<Option<i32>>>();
+//! assert_eq!(nums, &[Some(1), None]);
+//!
+//! let owned = list.iter().filter_map(pluck!(cloned .1)).collect::<Vec<String>>();
+//! assert_eq!(owned, &["a"]);
+//! ```
+//!
+//! `as_ref` and `as_mut` call the methods of the same name on the argument or
+//! the property that follows, turning an [`Option`] or [`Result`] property
+//! into one holding a shared or mutable reference.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Record { maybe: Option<i32> }
+//! let mut list = [Record { maybe: Some(1) }, Record { maybe: None }];
+//!
+//! list.iter_mut().filter_map(pluck!(as_mut .maybe)).for_each(|value| *value += 1);
+//! let present = list.iter().filter_map(pluck!(as_ref .maybe)).collect::<Vec<_>>();
+//! assert_eq!(present, &[&2]);
+//! ```
+//!
+//! All of these work with [`map_while`](Iterator::map_while) as well, which
+//! stops at the first [`None`] where [`filter_map`](Iterator::filter_map)
+//! would skip it. `clone` plucks an owned `Option<T>` for it, and `flatten`
+//! a borrowed one.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Record { maybe: Option<String> }
+//! let list = vec![
+//!     Record { maybe: Some("a".to_string()) },
+//!     Record { maybe: None },
+//!     Record { maybe: Some("c".to_string()) },
+//! ];
+//!
+//! let owned = list.iter().map_while(pluck!(clone .maybe)).collect::<Vec<String>>();
+//! assert_eq!(owned, &["a"]);
+//!
+//! let borrowed = list.iter().map_while(pluck!(flatten .maybe)).collect::<Vec<&String>>();
+//! assert_eq!(borrowed, &["a"]);
+//! ```
+//!
+//! ## Method Calls
+//!
+//! Call a method by following the name with parentheses.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = ["a", "bb", "ccc"];
+//!
+//! let lengths = list.iter().map(pluck!(.len())).collect::<Vec<_>>();
+//! assert_eq!(lengths, &[1, 2, 3]);
+//! ```
+//!
+//! Arguments are passed through as written, so they can borrow from the
+//! surrounding scope.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [[0, 1], [2, 3]];
+//! let index = 1;
+//!
+//! let second = list.iter().map(pluck!(.get(index))).collect::<Vec<_>>();
+//! assert_eq!(second, &[Some(&1), Some(&3)]);
+//! ```
+//!
+//! The result of the method is returned as is, so methods returning a
+//! reference borrow from the argument without needing `&`. A leading `&`
+//! adds another layer of reference, so it only applies when the call is
+//! followed by a field or index, like `&.as_slice()[0]`.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = vec!["a".to_string(), "b".to_string()];
+//!
+//! let strs = list.iter().map(pluck!(.as_str())).collect::<Vec<&str>>();
+//! assert_eq!(strs, &["a", "b"]);
+//! ```
+//!
+//! The same goes for methods returning borrowing iterators, like
+//! [`chars`](str::chars) or [`split_whitespace`](str::split_whitespace), so
+//! they can be flattened with [`flat_map`](Iterator::flat_map).
+//!
+//! ```
+//! # use pluck::*;
+//! let list = vec!["a b".to_string(), "c".to_string()];
+//!
+//! let words = list.iter().flat_map(pluck!(.split_whitespace())).collect::<Vec<_>>();
+//! assert_eq!(words, &["a", "b", "c"]);
+//! ```
+//!
+//! Methods can take generic arguments with the turbofish.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [("1", 'a'), ("2", 'b')];
+//!
+//! let parsed = list.iter().map(pluck!(.0.parse::<i32>())).collect::<Vec<_>>();
+//! assert_eq!(parsed, &[Ok(1), Ok(2)]);
+//! ```
+//!
+//! Follow an [`Option`] or [`Result`] with `unwrap` to pluck its contents,
+//! panicking if there are none.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [vec![0, 1], vec![2, 3]];
+//!
+//! let second = list.iter().map(pluck!(.get(1) unwrap)).collect::<Vec<_>>();
+//! assert_eq!(second, &[&1, &3]);
+//!
+//! let list = ["1", "2"];
+//! let parsed = list.iter().map(pluck!(.parse::<i32>() unwrap)).collect::<Vec<_>>();
+//! assert_eq!(parsed, &[1, 2]);
+//! ```
+//!
 //! ## Index Type
 //!
 //! `pluck!` works with types implementing [`Index`](std::ops::Index) and
-//! [`IndexMut`](std::ops::IndexMut).
+//! [`IndexMut`](std::ops::IndexMut), including through [`Deref`](std::ops::Deref)
+//! as in Rust, so `Box<[T]>` and `Rc<[T]>` index like slices.
 //!
 //! ```
 //! # use pluck::*;
@@ -77,6 +463,86 @@
 //! assert_eq!(first_ref, &[&0, &1, &2]);
 //! ```
 //!
+//! The index can be any expression, including ones using local variables or
+//! const generic parameters. Ranges pluck sub-slices.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [vec![0, 1, 2], vec![3, 4, 5]];
+//!
+//! let i = 1;
+//! let next = list.iter().map(pluck!([i + 1])).collect::<Vec<_>>();
+//! assert_eq!(next, &[2, 5]);
+//!
+//! let middle = list.iter().map(pluck!(&[1..3])).collect::<Vec<_>>();
+//! assert_eq!(middle, &[&[1, 2], &[4, 5]]);
+//! ```
+//!
+//! [`split!`] takes a property and an index separated by `,`, and creates a
+//! lambda returning both halves as `(&[..n], &[n..])`, like
+//! [`split_at`](slice::split_at). It panics if the index is past the end.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Packet { data: Vec<u8> }
+//! let list = [Packet { data: vec![1, 2, 3] }, Packet { data: vec![4, 5] }];
+//!
+//! let halves = list.iter().map(split!(.data, 1)).collect::<Vec<_>>();
+//! assert_eq!(halves, &[(&[1][..], &[2, 3][..]), (&[4][..], &[5][..])]);
+//! ```
+//!
+//! Ranges over [`String`]s pluck `&str`s by byte. Like slicing directly, they
+//! panic if a bound is not on a `char` boundary. With `&mut`, they pluck
+//! `&mut str`s from mutably borrowed [`String`]s.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = vec!["apple".to_string(), "banana".to_string()];
+//!
+//! let prefixes = list.iter().map(pluck!(&[0..3])).collect::<Vec<&str>>();
+//! assert_eq!(prefixes, &["app", "ban"]);
+//! ```
+//!
+//! Start the index with `^` to count from the end, so `[^1]` is the last
+//! element of a slice, array or [`Vec`]. It panics unless the count is
+//! between 1 and the length.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [vec![0, 1, 2], vec![3, 4, 5]];
+//!
+//! let last = list.iter().map(pluck!([^1])).collect::<Vec<_>>();
+//! assert_eq!(last, &[2, 5]);
+//!
+//! let second_last = list.iter().map(pluck!(&[^2])).collect::<Vec<_>>();
+//! assert_eq!(second_last, &[&1, &4]);
+//! ```
+//!
+//! Precede the brackets with `get` to look up with `get` instead, which
+//! returns an [`Option`] rather than panicking on a missing key.
+//!
+//! ```
+//! # use pluck::*;
+//! use std::collections::HashMap;
+//!
+//! let list = [HashMap::from([("a", 1)]), HashMap::from([("b", 2)])];
+//!
+//! let a = list.iter().map(pluck!(get["a"])).collect::<Vec<_>>();
+//! assert_eq!(a, &[Some(&1), None]);
+//! ```
+//!
+//! Use `get_mut` for an [`Option`] of a mutable reference.
+//!
+//! ```
+//! # use pluck::*;
+//! use std::collections::HashMap;
+//!
+//! let mut list = [HashMap::from([("a", 1)]), HashMap::from([("b", 2)])];
+//!
+//! list.iter_mut().filter_map(pluck!(get_mut["a"])).for_each(|a| *a += 1);
+//! assert_eq!(list[0]["a"], 2);
+//! ```
+//!
 //! ## Deref
 //!
 //! `pluck!` works with types implementing [`Deref`](std::ops::Deref) and
@@ -92,110 +558,4612 @@
 //! let derefed = list.into_iter().map(pluck!(***)).collect::<Vec<_>>();
 //! assert_eq!(derefed, &[0, 1, 2]);
 //! ```
-//! 
-//! # Combinations
-//! 
-//! `pluck!` is designed to allow you to arbitrarily combine accessing. You
-//! can specify precedence with `()`.
-//! 
+//!
+//! Follow `*` with a count from 1 to 8 to deref that many times.
+//!
 //! ```
 //! # use pluck::*;
-//! struct Person { name: &'static str }
-//! let mut list = vec![[&Person { name: "Alice" }]];
-//! let derefed = list.iter_mut().map(pluck!((*[0]).name)).collect::<Vec<_>>();
-//! assert_eq!(derefed, &["Alice"]);
+//! let list = vec![&&&0, &&&1, &&&2];
+//! let derefed = list.into_iter().map(pluck!(*3)).collect::<Vec<_>>();
+//! assert_eq!(derefed, &[0, 1, 2]);
 //! ```
-
-#[doc(hidden)]
-#[macro_export]
-macro_rules! do_expression {
-    ($var:expr, ($($exprs:tt)*)$($tail:tt)*) => {
-        $crate::do_expression!($crate::do_expression!($var, $($exprs)*), $($tail)*)
-    };
-    ($var:expr, [$expr:tt]$($tail:tt)*) => {
-        $crate::do_expression!($var[$expr], $($tail)*)
-    };
-    ($var:expr, *$($tail:tt)*) => {
-        *$crate::do_expression!($var, $($tail)*)
-    };
-    ($var:expr, .$expr:tt$($tail:tt)*) => {
-        $crate::do_expression!($var.$expr, $($tail)*)
-    };
-    ($var:expr,) => {
-        $var
-    }
-}
-
-/// Create a lambda that extracts the provided property from the argument.
-///
+//!
+//! `deref` and `deref_mut` call [`Deref::deref`](std::ops::Deref::deref) and
+//! [`DerefMut::deref_mut`](std::ops::DerefMut::deref_mut) explicitly, on the
+//! argument or on the property that follows.
+//!
+//! ```
+//! # use pluck::*;
+//! let mut list = vec![Box::new(0), Box::new(1)];
+//!
+//! list.iter_mut().map(pluck!(deref_mut)).for_each(|value| *value += 1);
+//! let derefed = list.iter().map(pluck!(deref)).collect::<Vec<&i32>>();
+//! assert_eq!(derefed, &[&1, &2]);
+//! ```
+//!
+//! As in Rust, fields and methods resolve on the argument itself before its
+//! [`Deref`](std::ops::Deref) target, so `.0` on a newtype is its own field.
+//! Deref in a group, like `(**).0`, to reach the target's field instead.
+//!
+//! ```
+//! # use pluck::*;
+//! # use std::ops::Deref;
+//! struct Wrapper((&'static str, i32));
+//! impl Deref for Wrapper {
+//!     type Target = (&'static str, i32);
+//!     fn deref(&self) -> &Self::Target { &self.0 }
+//! }
+//! let list = [Wrapper(("a", 1))];
+//!
+//! let own = list.iter().map(pluck!(.0)).collect::<Vec<_>>();
+//! assert_eq!(own, &[("a", 1)]);
+//!
+//! let target = list.iter().map(pluck!((**).0)).collect::<Vec<_>>();
+//! assert_eq!(target, &["a"]);
+//! ```
+//!
+//! A type has a single [`Deref`](std::ops::Deref) target, so each `*` is
+//! never ambiguous: from `&String`, `&**` plucks a `&str`, and from
+//! `&Vec<T>` a `&[T]`. To name the target instead of counting derefs, ascribe
+//! it as in [Type Ascription](#type-ascription), which applies the same deref
+//! coercions as a `let` with a type.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = vec!["a".to_string(), "bc".to_string()];
+//!
+//! let counted = list.iter().map(pluck!(&**)).collect::<Vec<&str>>();
+//! let named = list.iter().map(pluck!(&*: &str)).collect::<Vec<_>>();
+//! assert_eq!(counted, named);
+//! ```
+//! 
+//! ## Raw Pointers
+//!
+//! Precede the access with `unsafe` to run it in an `unsafe` block, such as
+//! to deref raw pointers. Every pointer derefed this way must be valid for
+//! reads, or writes for `&mut`, for as long as the plucked value is used, as
+//! if it were derefed by hand.
+//!
+//! ```
+//! # use pluck::*;
+//! let values = [(1, 'a'), (2, 'b')];
+//! let list = values.iter().map(|value| value as *const (i32, char)).collect::<Vec<_>>();
+//!
+//! // SAFETY: The pointers come from references into `values`, which is alive.
+//! let nums = list.iter().map(pluck!(unsafe (**).0)).collect::<Vec<_>>();
+//! assert_eq!(nums, &[1, 2]);
+//! ```
+//!
+//! `assume_init` borrows the contents of a
+//! [`MaybeUninit`](core::mem::MaybeUninit) argument or property with
+//! [`assume_init_ref`](core::mem::MaybeUninit::assume_init_ref). It is only
+//! allowed after `unsafe`, and every value read must be initialized.
+//! [`ManuallyDrop`](core::mem::ManuallyDrop) needs neither, since it derefs
+//! to its contents like any smart pointer.
+//!
+//! ```
+//! # use pluck::*;
+//! use std::mem::{ManuallyDrop, MaybeUninit};
+//!
+//! let list = [(MaybeUninit::new(1), ManuallyDrop::new(2))];
+//!
+//! // SAFETY: Every `MaybeUninit` in `list` is initialized.
+//! let inits = list.iter().map(pluck!(unsafe assume_init .0)).collect::<Vec<_>>();
+//! assert_eq!(inits, &[&1]);
+//!
+//! let manual = list.iter().map(pluck!(*.1)).collect::<Vec<_>>();
+//! assert_eq!(manual, &[2]);
+//! ```
+//!
+//! ## Interior Mutability
+//!
+//! Copy values out of a [`Cell`](core::cell::Cell) with `.get()`. For a
+//! [`RefCell`](core::cell::RefCell), `borrow` and `borrow_mut` return the
+//! guard for the argument or the property that follows.
+//!
+//! ```
+//! # use pluck::*;
+//! use std::cell::{Cell, RefCell};
+//!
+//! let list = [(Cell::new(1), RefCell::new(vec![1])), (Cell::new(2), RefCell::new(vec![2]))];
+//!
+//! let numbers = list.iter().map(pluck!(.0.get())).collect::<Vec<_>>();
+//! assert_eq!(numbers, &[1, 2]);
+//!
+//! list.iter().map(pluck!(borrow_mut .1)).for_each(|mut guard| guard.push(0));
+//! let lens = list.iter().map(pluck!(borrow .1)).map(|guard| guard.len()).collect::<Vec<_>>();
+//! assert_eq!(lens, &[2, 2]);
+//! ```
+//!
+//! `lock` locks a [`Mutex`](std::sync::Mutex), including one behind an
+//! [`Arc`](std::sync::Arc), and copies the property that follows out of the
+//! guard, so the lock is released before the lambda returns. Use `lock clone`
+//! for properties that are only [`Clone`]. Both panic if the mutex is
+//! poisoned.
+//!
+//! ```
+//! # use pluck::*;
+//! use std::sync::{Arc, Mutex};
+//!
+//! struct Counter { hits: u32, name: String }
+//! let list = vec![Arc::new(Mutex::new(Counter { hits: 1, name: "a".to_string() }))];
+//!
+//! let hits = list.iter().map(pluck!(lock .hits)).collect::<Vec<u32>>();
+//! assert_eq!(hits, &[1]);
+//!
+//! let names = list.iter().map(pluck!(lock clone .name)).collect::<Vec<String>>();
+//! assert_eq!(names, &["a"]);
+//! ```
+//!
+//! ## Enum Variants
+//!
+//! Name a variant by its path and the field to extract, like `E::A(.0)`, to
+//! get an [`Option`] that is [`None`] for other variants. This pairs well with
+//! [`filter_map`](Iterator::filter_map). Fields `.0` through `.5` are
+//! supported, and may be followed by further access.
+//!
+//! ```
+//! # use pluck::*;
+//! enum E { A(i32), B(String) }
+//! let list = vec![E::A(1), E::B("b".to_string()), E::A(2)];
+//!
+//! let a = list.iter().filter_map(pluck!(E::A(.0))).collect::<Vec<_>>();
+//! assert_eq!(a, &[&1, &2]);
+//!
+//! let b = list.iter().filter_map(pluck!(E::B(.0.len()))).collect::<Vec<_>>();
+//! assert_eq!(b, &[1]);
+//! ```
+//!
+//! Name a field in braces, like `E::Point { x }`, to extract it from a variant
+//! with named fields. The other fields are ignored, with or without a
+//! trailing `..`.
+//!
+//! ```
+//! # use pluck::*;
+//! enum Shape { Point { x: i32, y: i32 }, Empty }
+//! let list = vec![Shape::Point { x: 1, y: 2 }, Shape::Empty];
+//!
+//! let xs = list.iter().filter_map(pluck!(Shape::Point { x })).collect::<Vec<_>>();
+//! assert_eq!(xs, &[&1]);
+//!
+//! let ys = list.iter().filter_map(pluck!(Shape::Point { y, .. })).collect::<Vec<_>>();
+//! assert_eq!(ys, &[&2]);
+//! ```
+//!
+//! ## Destructuring
+//!
+//! Precede a tuple struct pattern with `let` to destructure the argument and
+//! extract one of its fields. Given a reference, the field is bound by
+//! reference.
+//!
+//! The `let` is required: a macro cannot tell whether a bare `Wrapper(.0)`
+//! names a struct, which always matches, or a glob-imported enum variant,
+//! which may not, so without it the name is called as a function instead
+//! (see [Function Calls](#function-calls)).
+//!
+//! ```
+//! # use pluck::*;
+//! struct Wrapper(i32, &'static str);
+//! let list = vec![Wrapper(0, "a"), Wrapper(1, "b")];
+//!
+//! let names = list.iter().map(pluck!(let Wrapper(.1))).collect::<Vec<_>>();
+//! assert_eq!(names, &[&"a", &"b"]);
+//!
+//! let numbers = list.into_iter().map(pluck!(let Wrapper(.0))).collect::<Vec<_>>();
+//! assert_eq!(numbers, &[0, 1]);
+//! ```
+//!
+//! ## Type Ascription
+//!
+//! End with `: Type` to annotate the output type, which helps inference when
+//! the result is otherwise ambiguous.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [("a", 1), ("b", 2)];
+//!
+//! let numbers = list.iter().map(pluck!(.1: u64)).collect::<Vec<_>>();
+//! assert_eq!(numbers, &[1u64, 2]);
+//!
+//! let numbers = list.iter().map(pluck!(&.1: &u64)).collect::<Vec<_>>();
+//! assert_eq!(numbers, &[&1, &2]);
+//! ```
+//!
+//! With [Optional Chaining](#optional-chaining), the type is that of the
+//! unwrapped value, before it is wrapped in [`Some`]. Starting the access
+//! with `?` is the exception, since the type then applies to the whole
+//! [`Option`].
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [(Some("a".to_string()),), (None,)];
+//!
+//! let names = list.iter().map(pluck!(&.0?: &str)).collect::<Vec<Option<&str>>>();
+//! assert_eq!(names, &[Some("a"), None]);
+//! ```
+//!
+//! ## Move
+//!
+//! Start with `move` to move captured variables into the lambda, like a
+//! `move` closure. This lets the lambda outlive the variables it uses.
+//!
+//! ```
+//! # use pluck::*;
+//! fn nth(index: usize) -> impl Fn(&Vec<i32>) -> &i32 {
+//!     pluck!(move &[index])
+//! }
+//!
+//! let list = vec![vec![1, 2, 3], vec![4, 5, 6]];
+//! let second = list.iter().map(nth(1)).collect::<Vec<_>>();
+//! assert_eq!(second, &[&2, &5]);
+//! ```
+//!
+//! ## Function Calls
+//!
+//! Wrap properties in a call to a function to pluck its result. Each argument
+//! supports all of the access forms above.
+//!
+//! ```
+//! # use pluck::*;
+//! fn double(value: i32) -> i32 { value * 2 }
+//! fn join(a: &str, b: &str) -> String { format!("{}{}", a, b) }
+//! let list = [(1, "a"), (2, "b")];
+//!
+//! let doubled = list.iter().map(pluck!(double(.0))).collect::<Vec<_>>();
+//! assert_eq!(doubled, &[2, 4]);
+//!
+//! let joined = list.iter().map(pluck!(join(.1, .1))).collect::<Vec<_>>();
+//! assert_eq!(joined, &["aa", "bb"]);
+//! ```
+//!
+//! Keywords take precedence over functions of the same name, so a function
+//! named `copy`, `clone`, `ok`, `err`, `flatten`, `lock`, `borrow`, `deref`,
+//! `unwrap`, or any other keyword above is never called. `copy(.0)` copies
+//! `.0` instead. Call such functions from a closure, or rename them.
+//!
+//! Likewise, a tuple struct or enum variant named without a path, like
+//! `Wrapper(.0)`, is called as a constructor and builds a new value rather
+//! than matching the argument. Use `let Wrapper(.0)` from
+//! [Destructuring](#destructuring) to extract the field instead.
+//!
+//! ```
+//! # use pluck::*;
+//! #[derive(Debug, PartialEq)]
+//! struct Wrapper(u32);
+//! let list = [Wrapper(1), Wrapper(2)];
+//!
+//! let fields = list.iter().map(pluck!(let Wrapper(.0))).collect::<Vec<&u32>>();
+//! assert_eq!(fields, &[&1, &2]);
+//!
+//! let wrapped = list.iter().map(pluck!(Wrapper(.0))).collect::<Vec<_>>();
+//! assert_eq!(wrapped, &[Wrapper(1), Wrapper(2)]);
+//! ```
+//!
+//! # Combinations
+//! 
+//! `pluck!` is designed to allow you to arbitrarily combine accessing. You
+//! can specify precedence with `()`.
+//! 
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str }
+//! let mut list = vec![[&Person { name: "Alice" }]];
+//! let derefed = list.iter_mut().map(pluck!((*[0]).name)).collect::<Vec<_>>();
+//! assert_eq!(derefed, &["Alice"]);
+//! ```
+//!
+//! # Multiple Properties
+//!
+//! Separate accesses with `,` to pluck several properties into a tuple. Each
+//! element supports all of the access forms above.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str, age: u32 }
+//! let list = [Person { name: "Alice", age: 30 }, Person { name: "Bob", age: 25 }];
+//!
+//! let pairs = list.iter().map(pluck!(.name, .age)).collect::<Vec<_>>();
+//! assert_eq!(pairs, &[("Alice", 30), ("Bob", 25)]);
+//!
+//! let refs = list.iter().map(pluck!(&.age, .name)).collect::<Vec<_>>();
+//! assert_eq!(refs, &[(&30, "Alice"), (&25, "Bob")]);
+//! ```
+//!
+//! Listing the elements of a tuple in a different order reorders it.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [(1, "a"), (2, "b")];
+//!
+//! let swapped = list.iter().map(pluck!(.1, .0)).collect::<Vec<_>>();
+//! assert_eq!(swapped, &[("a", 1), ("b", 2)]);
+//! ```
+//!
+//! Disjoint properties can be mutably borrowed together.
+//!
+//! ```
+//! # use pluck::*;
+//! let mut list = [(1, "a".to_string()), (2, "b".to_string())];
+//!
+//! for (num, name) in list.iter_mut().map(pluck!(&mut .0, &mut .1)) {
+//!     *num += 1;
+//!     name.push('!');
+//! }
+//! assert_eq!(list, [(2, "a!".to_string()), (3, "b!".to_string())]);
+//! ```
+//!
+//! Wrap the accesses in brackets to pluck copies of them into an array
+//! instead. The properties must all have the same [`Copy`] type, and the
+//! array's length is fixed by the number of accesses. A single access needs
+//! a trailing `,`, since `[...]` alone is an index.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Particle { x: f64, y: f64, z: f64 }
+//! let list = vec![Particle { x: 1.0, y: 2.0, z: 3.0 }];
+//!
+//! let points = list.iter().map(pluck!([.x, .y, .z])).collect::<Vec<[f64; 3]>>();
+//! assert_eq!(points, &[[1.0, 2.0, 3.0]]);
+//! ```
+//!
+//! Wrap them in `vec![...]` to pluck the copies into a [`Vec`] instead. This
+//! needs the default `alloc` feature.
+//!
+//! ```
+//! # use pluck::*;
+//! # #[cfg(feature = "alloc")] {
+//! struct Color { r: u8, g: u8, b: u8 }
+//! let list = vec![Color { r: 255, g: 128, b: 0 }];
+//!
+//! let channels = list.iter().map(pluck!(vec![.r, .g, .b])).collect::<Vec<Vec<u8>>>();
+//! assert_eq!(channels, &[vec![255, 128, 0]]);
+//! # }
+//! ```
+//!
+//! # Arithmetic
+//!
+//! Two plucks can be combined with `+`, `-`, `*`, or `/`. This is not a
+//! general expression grammar: exactly one operator is supported, and each
+//! side is a single access. After `as` or `into`, an operator is part of the
+//! type, like in `as *const T`, unless a property follows it.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Rect { width: u32, height: u32 }
+//! let list = [Rect { width: 2, height: 3 }, Rect { width: 4, height: 5 }];
+//!
+//! let areas = list.iter().map(pluck!(.width * .height)).collect::<Vec<_>>();
+//! assert_eq!(areas, &[6, 20]);
+//! ```
+//!
+//! # Sorting
+//!
+//! [`sort_by_key`](slice::sort_by_key) needs keys that do not borrow from the
+//! element. [`sort_key!`] clones each plucked property to produce one.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: String, age: u32 }
+//! let mut list = vec![
+//!     Person { name: "Bob".to_string(), age: 30 },
+//!     Person { name: "Alice".to_string(), age: 30 },
+//!     Person { name: "Carol".to_string(), age: 25 },
+//! ];
+//!
+//! list.sort_by_key(sort_key!(.name));
+//! assert_eq!(list.iter().map(pluck!(&.name)).collect::<Vec<_>>(), &["Alice", "Bob", "Carol"]);
+//!
+//! list.sort_by_key(sort_key!(.age, .name));
+//! assert_eq!(list.iter().map(pluck!(&.name)).collect::<Vec<_>>(), &["Carol", "Alice", "Bob"]);
+//! ```
+//!
+//! The same keys work with [`min_by_key`](Iterator::min_by_key),
+//! [`max_by_key`](Iterator::max_by_key) and
+//! [`is_sorted_by_key`](slice::is_sorted_by_key).
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: String, age: u32 }
+//! let list = vec![
+//!     Person { name: "Bob".to_string(), age: 30 },
+//!     Person { name: "Carol".to_string(), age: 25 },
+//! ];
+//!
+//! assert!(!list.is_sorted_by_key(sort_key!(.age)));
+//! assert!(list.is_sorted_by_key(sort_key!(.name)));
+//!
+//! let youngest = list.into_iter().min_by_key(sort_key!(.age)).unwrap();
+//! assert_eq!(youngest.name, "Carol");
+//! ```
+//!
+//! [`dedup_key!`] creates the same owned keys for
+//! [`dedup_by_key`](Vec::dedup_by_key), which passes each element by mutable
+//! reference.
+//!
+//! ```
+//! # use pluck::*;
+//! let mut list = vec![(1, "a"), (1, "b"), (2, "c"), (1, "d")];
+//!
+//! list.dedup_by_key(dedup_key!(.0));
+//! assert_eq!(list, &[(1, "a"), (2, "c"), (1, "d")]);
+//! ```
+//!
+//! [`cmp_by!`] instead creates a comparator for [`sort_by`](slice::sort_by),
+//! which compares the properties in place without cloning. Precede the
+//! property with `rev` to sort in descending order.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: String }
+//! let mut list = vec![
+//!     Person { name: "Bob".to_string() },
+//!     Person { name: "Alice".to_string() },
+//! ];
+//!
+//! list.sort_by(cmp_by!(.name));
+//! assert_eq!(list.iter().map(pluck!(&.name)).collect::<Vec<_>>(), &["Alice", "Bob"]);
+//!
+//! list.sort_by(cmp_by!(rev .name));
+//! assert_eq!(list.iter().map(pluck!(&.name)).collect::<Vec<_>>(), &["Bob", "Alice"]);
+//! ```
+//!
+//! # Defaults
+//!
+//! [`pluck_or!`] takes an access that produces an [`Option`] followed by a
+//! default to use in place of [`None`]. Everything after the first `,` is the
+//! default, so it can be any expression, like `HashMap::<K, V>::new()`.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [vec![0, 1], vec![2]];
+//!
+//! let second = list.iter().map(pluck_or!(.get(1), &-1)).collect::<Vec<_>>();
+//! assert_eq!(second, &[&1, &-1]);
+//!
+//! let second = list.iter().map(pluck_or!(.get(1).copied(), -1)).collect::<Vec<_>>();
+//! assert_eq!(second, &[1, -1]);
+//! ```
+//!
+//! [`pluck_or_else!`] takes a closure instead, which is only called to
+//! compute the default when needed.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = [vec![0, 1], vec![2]];
+//!
+//! let second = list.iter().map(pluck_or_else!(.get(1).copied(), || -1)).collect::<Vec<_>>();
+//! assert_eq!(second, &[1, -1]);
+//! ```
+//!
+//! # Conditions
+//!
+//! [`pluck_if!`] takes a [`bool`] access followed by `=>` and the property to
+//! return in [`Some`] when it is `true`, for use with
+//! [`filter_map`](Iterator::filter_map). Like [`sort_key!`], each property
+//! after `=>` is cloned so the result does not borrow from the item.
+//!
+//! ```
+//! # use pluck::*;
+//! struct User { name: String, active: bool }
+//! let list = vec![
+//!     User { name: "Alice".to_string(), active: true },
+//!     User { name: "Bob".to_string(), active: false },
+//! ];
+//!
+//! let active = list.iter().filter_map(pluck_if!(.active => .name)).collect::<Vec<String>>();
+//! assert_eq!(active, &["Alice"]);
+//!
+//! let inactive = list.iter().filter_map(pluck_if!(!.active => .name.len())).collect::<Vec<_>>();
+//! assert_eq!(inactive, &[3]);
+//! ```
+//!
+//! # Collecting
+//!
+//! [`pluck_collect!`] plucks from every item of an iterable and collects the
+//! results into the collection type given after `=>`.
+//!
+//! ```
+//! # use pluck::*;
+//! use std::collections::HashSet;
+//!
+//! let list = [("a", 1), ("b", 2), ("c", 1)];
+//!
+//! let names = pluck_collect!(&list, .0 => Vec<_>);
+//! assert_eq!(names, &["a", "b", "c"]);
+//!
+//! let numbers = pluck_collect!(&list, .1 => HashSet<_>);
+//! assert_eq!(numbers, HashSet::from([1, 2]));
+//! ```
+//!
+//! Separate a key and a value with `=>` to pluck a `(key, value)` pair for
+//! collecting into a map. Like [`map_by_key!`], the key is a clone of the
+//! property, while the value is plucked as written.
+//!
+//! ```
+//! # use pluck::*;
+//! use std::collections::HashMap;
+//!
+//! struct Person { id: u32, name: String }
+//! let list = vec![Person { id: 1, name: "Alice".to_string() }];
+//!
+//! let names = list.iter().map(pluck!(.id => clone .name)).collect::<HashMap<_, _>>();
+//! assert_eq!(names[&1], "Alice");
+//!
+//! let by_name = pluck_collect!(&list, .name => .id => HashMap<String, u32>);
+//! assert_eq!(by_name["Alice"], 1);
+//! ```
+//!
+//! # Aggregating
+//!
+//! [`pluck_sum!`] and [`pluck_product!`] pluck a numeric property from every
+//! item of an iterable and add or multiply them, producing the property's own
+//! type.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Transaction { amount: f64 }
+//! let list = [Transaction { amount: 1.5 }, Transaction { amount: 2.0 }];
+//!
+//! assert_eq!(pluck_sum!(&list, .amount), 3.5);
+//! assert_eq!(pluck_product!(&list, .amount), 3.0);
+//! ```
+//!
+//! # Accumulating
+//!
+//! [`pluck_scan!`] creates a lambda for [`scan`](Iterator::scan) that applies
+//! a compound assignment of the plucked property to the state, then yields a
+//! clone of the new state.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Record { delta: i32 }
+//! let list = [Record { delta: 1 }, Record { delta: 2 }, Record { delta: -4 }];
+//!
+//! let totals = list.iter().scan(0, pluck_scan!(+= .delta)).collect::<Vec<_>>();
+//! assert_eq!(totals, &[1, 3, -1]);
+//! ```
+//!
+//! [`combine!`] creates a lambda for [`fold`](Iterator::fold) instead, which
+//! combines the accumulator with the plucked property using `+`, `*`, `min`
+//! or `max`.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Record { amount: f64 }
+//! let list = [Record { amount: 1.5 }, Record { amount: 3.0 }];
+//!
+//! assert_eq!(list.iter().fold(0.0, combine!(+ .amount)), 4.5);
+//! assert_eq!(list.iter().fold(f64::MAX, combine!(min .amount)), 1.5);
+//! ```
+//!
+//! # Grouping
+//!
+//! [`pluck_group_by!`] consumes an iterable and groups its items into a
+//! [`HashMap`](std::collections::HashMap) keyed by a clone of the plucked
+//! property, so the key must implement [`Clone`], [`Eq`] and
+//! [`Hash`](core::hash::Hash).
+//!
+//! ```
+//! # use pluck::*;
+//! struct Item { category: String, price: u32 }
+//! let list = vec![
+//!     Item { category: "fruit".to_string(), price: 1 },
+//!     Item { category: "bread".to_string(), price: 3 },
+//!     Item { category: "fruit".to_string(), price: 2 },
+//! ];
+//!
+//! let groups = pluck_group_by!(list, .category);
+//! assert_eq!(
+//!     groups["fruit"].iter().map(pluck!(.price)).collect::<Vec<_>>(),
+//!     &[1, 2],
+//! );
+//! ```
+//!
+//! [`map_by_key!`] builds a lookup table instead, keeping the last item for
+//! each key.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Item { id: u32, name: &'static str }
+//! let list = vec![Item { id: 1, name: "a" }, Item { id: 2, name: "b" }, Item { id: 1, name: "c" }];
+//!
+//! let by_id = map_by_key!(list, .id);
+//! assert_eq!(by_id[&1].name, "c");
+//! assert_eq!(by_id[&2].name, "b");
+//! ```
+//!
+//! Both build a different map type, such as
+//! [`BTreeMap`](std::collections::BTreeMap) for sorted keys, when its name
+//! follows `=>`. It must have `new`, plus `entry` for [`pluck_group_by!`] or
+//! `insert` for [`map_by_key!`].
+//!
+//! ```
+//! # use pluck::*;
+//! use std::collections::BTreeMap;
+//!
+//! let list = vec![("b", 1), ("a", 2), ("b", 3)];
+//!
+//! let groups = pluck_group_by!(list.clone(), .0 => BTreeMap);
+//! assert!(groups.keys().eq(&["a", "b"]));
+//!
+//! let by_name = map_by_key!(list, .0 => std::collections::BTreeMap);
+//! assert!(by_name.values().eq(&[("a", 2), ("b", 3)]));
+//! ```
+//!
+//! # Inspecting
+//!
+//! [`inspect_pluck!`] plucks a property and passes it to the action following
+//! `=>`, for side effects in [`inspect`](Iterator::inspect) or
+//! [`for_each`](Iterator::for_each). The action can be any closure or function
+//! taking the plucked value. The lambda captures variables used by the
+//! property and the action by reference, like [`pluck!`], and evaluates the
+//! action each time it is called.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Event { name: &'static str, size: usize }
+//! let list = [Event { name: "open", size: 1 }, Event { name: "close", size: 2 }];
+//!
+//! let mut names = Vec::new();
+//! let total = list
+//!     .iter()
+//!     .inspect(inspect_pluck!(.name => |name| names.push(name)))
+//!     .map(pluck!(.size))
+//!     .sum::<usize>();
+//! assert_eq!((names, total), (vec!["open", "close"], 3));
+//! ```
+//!
+//! # Named Functions
+//!
+//! Lambdas are [`Copy`] and [`Clone`] as long as everything they capture is,
+//! which is always the case without `move`, so one lambda can already be
+//! passed to several adapters. For this reason there is no expression form
+//! like `let f = pluck_fn!(.name);`.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str }
+//! let list = [Person { name: "Alice" }, Person { name: "Bob" }];
+//!
+//! fn twice<T, R>(list: &[T], f: impl Fn(&T) -> R + Copy) -> (Vec<R>, Vec<R>) {
+//!     (list.iter().map(f).collect(), list.iter().rev().map(f).collect())
+//! }
+//!
+//! let (names, reversed) = twice(&list, pluck!(.name));
+//! assert_eq!((names, reversed), (vec!["Alice", "Bob"], vec!["Bob", "Alice"]));
+//! ```
+//!
+//! Their types cannot be named, though. [`pluck_fn!`] defines a function item
+//! instead, which is zero-sized, [`Copy`], and coerces to a `fn` pointer that
+//! can be stored in a struct field or returned.
+//! The function is `#[inline(always)]`, and should not be given another
+//! `#[inline]` attribute. It is a function rather than a struct implementing
+//! [`Fn`], since implementing [`Fn`] by hand needs nightly Rust. The `access`
+//! benchmark times it against a hand-written closure, but nothing checks
+//! that the generated code is identical.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str }
+//! pluck_fn!(fn name(&Person) -> &'static str = .name);
+//!
+//! let list = [Person { name: "Alice" }, Person { name: "Bob" }];
+//! let names = list.iter().map(name).collect::<Vec<_>>();
+//! assert_eq!(names, &["Alice", "Bob"]);
+//!
+//! let stored: fn(&Person) -> &'static str = name;
+//! assert_eq!(stored(&list[1]), "Bob");
+//! ```
+//!
+//! # Iterator Extension
+//!
+//! [`PluckExt`] adds a [`pluck`](PluckExt::pluck) method to every iterator as
+//! a more discoverable spelling of `map`.
+//!
+//! ```
+//! # use pluck::*;
+//! struct Person { name: &'static str, age: u32 }
+//! let list = [Person { name: "Alice", age: 30 }, Person { name: "Bob", age: 25 }];
+//!
+//! let names = list
+//!     .iter()
+//!     .filter(|person| person.age > 26)
+//!     .pluck(pluck!(.name))
+//!     .collect::<Vec<_>>();
+//! assert_eq!(names, &["Alice"]);
+//! ```
+//!
+//! # Without Macros
+//!
+//! The [`Pluck`] trait extracts tuple elements by a marker type, and indexes
+//! anything implementing [`Index`](core::ops::Index), for contexts where a
+//! macro is inconvenient.
+//!
+//! ```
+//! # use pluck::*;
+//! let list = vec![(0, "a"), (1, "b")];
+//!
+//! let second = list.into_iter().map(|pair| pair.pluck(Idx1)).collect::<Vec<_>>();
+//! assert_eq!(second, &["a", "b"]);
+//! ```
+//!
+//! # Procedural Macro
+//!
+//! With the `proc-macro` feature, `parsed::pluck!` provides a `pluck!` that
+//! parses its input as Rust syntax instead of matching tokens. It accepts a
+//! restricted grammar of `&`, `&mut`, `*`, `!`, `clone` and `copy` prefixes
+//! followed by fields, indices, method calls and `?`, but handles any
+//! expression inside method arguments and indices.
+//!
+//! # `no_std`
+//!
+//! `pluck` is `#![no_std]`. The lambdas it generates only rely on [`core`].
+//! [`pluck_group_by!`] and [`map_by_key!`] are the exceptions, since they
+//! build a `HashMap` from `std`, along with `lock`, which locks a `Mutex`
+//! from `std`. Plucking into a `vec![...]` needs [`alloc`], through the
+//! default `alloc` feature, so `no_std` builds without an allocator should
+//! set `default-features = false`.
+
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod index;
+
+pub use index::*;
+
+/// The procedural implementation of [`pluck!`](crate::pluck!).
+///
+/// See [crate level documentation](crate#procedural-macro) for the supported
+/// grammar.
+///
+/// ```
+/// use pluck::parsed::pluck;
+///
+/// let list = [((0, "a"), 1.5f64), ((1, "b"), 2.5)];
+///
+/// let names = list.iter().map(pluck!(.0.1)).collect::<Vec<_>>();
+/// assert_eq!(names, &["a", "b"]);
+///
+/// let clamped = list.iter().map(pluck!(.1.min(2.0))).collect::<Vec<_>>();
+/// assert_eq!(clamped, &[1.5, 2.0]);
+/// ```
+#[cfg(feature = "proc-macro")]
+pub mod parsed {
+    pub use pluck_macros::pluck;
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! do_expression {
+    ($var:expr, [] $($tail:tt)*) => {
+        ::core::compile_error!("expected an index inside `[]`")
+    };
+    ($var:expr, ($($exprs:tt)*)$($tail:tt)*) => {
+        $crate::do_expression!($crate::do_expression!($var, $($exprs)*), $($tail)*)
+    };
+    ($var:expr, [^$($index:tt)+]$($tail:tt)*) => {
+        $crate::do_expression!($var[..][$crate::__private::FromEnd($($index)+)], $($tail)*)
+    };
+    ($var:expr, [$($index:tt)+]$($tail:tt)*) => {
+        $crate::do_expression!($var[$($index)+], $($tail)*)
+    };
+    ($var:expr, get[$($key:tt)+]$($tail:tt)*) => {
+        $crate::do_expression!($var.get($($key)+), $($tail)*)
+    };
+    ($var:expr, get_mut[$($key:tt)+]$($tail:tt)*) => {
+        $crate::do_expression!($var.get_mut($($key)+), $($tail)*)
+    };
+    ($var:expr, *8$($tail:tt)*) => {
+        *$crate::do_expression!($var, *7$($tail)*)
+    };
+    ($var:expr, *7$($tail:tt)*) => {
+        *$crate::do_expression!($var, *6$($tail)*)
+    };
+    ($var:expr, *6$($tail:tt)*) => {
+        *$crate::do_expression!($var, *5$($tail)*)
+    };
+    ($var:expr, *5$($tail:tt)*) => {
+        *$crate::do_expression!($var, *4$($tail)*)
+    };
+    ($var:expr, *4$($tail:tt)*) => {
+        *$crate::do_expression!($var, *3$($tail)*)
+    };
+    ($var:expr, *3$($tail:tt)*) => {
+        *$crate::do_expression!($var, *2$($tail)*)
+    };
+    ($var:expr, *2$($tail:tt)*) => {
+        *$crate::do_expression!($var, *1$($tail)*)
+    };
+    ($var:expr, *1$($tail:tt)*) => {
+        *$crate::do_expression!($var, $($tail)*)
+    };
+    ($var:expr, *$($tail:tt)*) => {
+        *$crate::do_expression!($var, $($tail)*)
+    };
+    ($var:expr, unwrap $($tail:tt)*) => {
+        $crate::do_expression!($crate::__private::Unwrap::unwrap($var), $($tail)*)
+    };
+    ($var:expr, ?$($tail:tt)*) => {
+        $crate::do_expression!((*$var.as_ref()?), $($tail)*)
+    };
+    ($var:expr, .$method:ident::<$($generic:ty),+>($($args:tt)*)$($tail:tt)*) => {
+        $crate::do_expression!($var.$method::<$($generic),+>($($args)*), $($tail)*)
+    };
+    ($var:expr, .$method:ident($($args:tt)*)$($tail:tt)*) => {
+        $crate::do_expression!($var.$method($($args)*), $($tail)*)
+    };
+    ($var:expr, .$expr:tt$($tail:tt)*) => {
+        $crate::do_expression!($var.$expr, $($tail)*)
+    };
+    ($var:expr, .) => {
+        ::core::compile_error!("expected a field, index, or method call after `.`")
+    };
+    ($var:expr,) => {
+        $var
+    };
+    ($var:expr, $next:tt $($tail:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected a field, index, method call, or deref, found `",
+            ::core::stringify!($next),
+            "`",
+        ))
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec;
+
+    #[inline(always)]
+    pub fn copy<T: Copy>(value: &T) -> T {
+        *value
+    }
+
+    #[inline(always)]
+    pub fn not(value: bool) -> bool {
+        !value
+    }
+
+    pub struct FromEnd(pub usize);
+
+    impl FromEnd {
+        #[inline(always)]
+        #[track_caller]
+        fn index(self, len: usize) -> usize {
+            if self.0 == 0 || self.0 > len {
+                panic!("index ^{} is out of range for length {}", self.0, len);
+            }
+            len - self.0
+        }
+    }
+
+    impl<T> core::ops::Index<FromEnd> for [T] {
+        type Output = T;
+
+        #[inline(always)]
+        #[track_caller]
+        fn index(&self, index: FromEnd) -> &T {
+            &self[index.index(self.len())]
+        }
+    }
+
+    impl<T> core::ops::IndexMut<FromEnd> for [T] {
+        #[inline(always)]
+        #[track_caller]
+        fn index_mut(&mut self, index: FromEnd) -> &mut T {
+            let index = index.index(self.len());
+            &mut self[index]
+        }
+    }
+
+    #[inline(always)]
+    pub fn inspect<T, F: FnMut(T)>(value: T, action: &mut F) {
+        action(value)
+    }
+
+    #[inline(always)]
+    pub fn sum<I>(iter: I) -> I::Item
+    where
+        I: Iterator,
+        I::Item: core::iter::Sum,
+    {
+        iter.sum()
+    }
+
+    #[inline(always)]
+    pub fn product<I>(iter: I) -> I::Item
+    where
+        I: Iterator,
+        I::Item: core::iter::Product,
+    {
+        iter.product()
+    }
+
+    /// Resolves `single_ref` to [`FromRef`] when the wrapped value is already
+    /// a reference, and to [`FromOwned`] through autoref otherwise.
+    pub struct SingleRef<'a, T: ?Sized>(pub &'a T);
+
+    pub trait FromRef<'b, U: ?Sized> {
+        fn single_ref(self) -> &'b U;
+    }
+
+    impl<'a, 'b, U: ?Sized> FromRef<'b, U> for SingleRef<'a, &'b U> {
+        #[inline(always)]
+        fn single_ref(self) -> &'b U {
+            self.0
+        }
+    }
+
+    pub trait FromOwned<'a, T: ?Sized> {
+        fn single_ref(self) -> &'a T;
+    }
+
+    impl<'a, T: ?Sized> FromOwned<'a, T> for &SingleRef<'a, T> {
+        #[inline(always)]
+        fn single_ref(self) -> &'a T {
+            self.0
+        }
+    }
+
+    #[diagnostic::on_unimplemented(
+        message = "`unwrap` expects an `Option` or `Result`, found `{Self}`",
+        label = "not an `Option` or `Result`"
+    )]
+    pub trait Unwrap {
+        type Output;
+
+        fn unwrap(self) -> Self::Output;
+    }
+
+    impl<T> Unwrap for Option<T> {
+        type Output = T;
+
+        #[inline(always)]
+        #[track_caller]
+        fn unwrap(self) -> T {
+            self.unwrap()
+        }
+    }
+
+    impl<T, E: core::fmt::Debug> Unwrap for Result<T, E> {
+        type Output = T;
+
+        #[inline(always)]
+        #[track_caller]
+        fn unwrap(self) -> T {
+            self.unwrap()
+        }
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! do_pluck {
+    (@suffix $var:expr, [] into $($ty:tt)*) => {
+        ::core::compile_error!("expected a property before `into`")
+    };
+    (@suffix $var:expr, [] as $($ty:tt)*) => {
+        ::core::compile_error!("expected a property before `as`")
+    };
+    (@suffix $var:expr, [$($lhs:tt)+] into) => {
+        ::core::compile_error!("expected a type after `into`")
+    };
+    (@suffix $var:expr, [$($lhs:tt)+] as) => {
+        ::core::compile_error!("expected a type after `as`")
+    };
+    (@suffix $var:expr, [] or_default) => {
+        ::core::compile_error!("expected a property before `or_default`")
+    };
+    (@suffix $var:expr, [$($lhs:tt)+] or_default) => {
+        ::core::option::Option::get_or_insert_with(
+            $crate::do_pluck!(@prefix $var, $($lhs)+),
+            ::core::default::Default::default,
+        )
+    };
+    (@suffix $var:expr, [$($lhs:tt)+] into $($ty:tt)+) => {
+        ::core::convert::Into::<$($ty)+>::into($crate::do_pluck!(@prefix $var, $($lhs)+))
+    };
+    (@suffix $var:expr, [$($lhs:tt)+] as $($ty:tt)+) => {
+        ($crate::do_pluck!(@prefix $var, $($lhs)+)) as $($ty)+
+    };
+    (@suffix $var:expr, [$($lhs:tt)*] .$next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@suffix $var, [$($lhs)* .$next] $($rest)*)
+    };
+    (@suffix $var:expr, [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@suffix $var, [$($lhs)* $next] $($rest)*)
+    };
+    (@suffix $var:expr, [$($lhs:tt)+]) => {
+        $crate::do_pluck!(@prefix $var, $($lhs)+)
+    };
+
+    (@prefix $var:expr, !) => {
+        ::core::compile_error!("expected a property after `!`")
+    };
+    (@prefix $var:expr, copy) => {
+        ::core::compile_error!("expected a property after `copy`")
+    };
+    (@prefix $var:expr, clone) => {
+        ::core::compile_error!("expected a property after `clone`")
+    };
+    (@prefix $var:expr, flatten) => {
+        ::core::compile_error!("expected a property after `flatten`")
+    };
+    (@prefix $var:expr, ok) => {
+        ::core::compile_error!("expected a property after `ok`")
+    };
+    (@prefix $var:expr, err) => {
+        ::core::compile_error!("expected a property after `err`")
+    };
+    (@prefix $var:expr, &mut) => {
+        ::core::compile_error!("expected a property after `&mut`")
+    };
+    (@prefix $var:expr, &&) => {
+        ::core::compile_error!("expected a property after `&&`")
+    };
+    (@prefix $var:expr, &) => {
+        ::core::compile_error!("expected a property after `&`")
+    };
+    (@prefix $var:expr, !$($expr:tt)+) => {
+        $crate::__private::not($crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, copy $($expr:tt)+) => {
+        $crate::__private::copy(&$crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, clone $($expr:tt)+) => {
+        ::core::clone::Clone::clone(&$crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, deref) => {
+        ::core::ops::Deref::deref($var)
+    };
+    (@prefix $var:expr, deref_mut) => {
+        ::core::ops::DerefMut::deref_mut($var)
+    };
+    (@prefix $var:expr, deref $($expr:tt)+) => {
+        ::core::ops::Deref::deref(&$crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, deref_mut $($expr:tt)+) => {
+        ::core::ops::DerefMut::deref_mut(&mut $crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, borrow) => {
+        ::core::cell::RefCell::borrow($var)
+    };
+    (@prefix $var:expr, borrow_mut) => {
+        ::core::cell::RefCell::borrow_mut($var)
+    };
+    (@prefix $var:expr, borrow $($expr:tt)+) => {
+        ::core::cell::RefCell::borrow(&$crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, borrow_mut $($expr:tt)+) => {
+        ::core::cell::RefCell::borrow_mut(&$crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, ref) => {
+        ::core::compile_error!("expected a property after `ref`")
+    };
+    (@prefix $var:expr, ref $($expr:tt)+) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{FromOwned as _, FromRef as _};
+        $crate::__private::SingleRef(&$crate::do_expression!($var, $($expr)+)).single_ref()
+    }};
+    (@prefix $var:expr, as_ref) => {
+        $var.as_ref()
+    };
+    (@prefix $var:expr, as_mut) => {
+        $var.as_mut()
+    };
+    (@prefix $var:expr, as_ref $($expr:tt)+) => {
+        $crate::do_expression!($var, $($expr)+).as_ref()
+    };
+    (@prefix $var:expr, as_mut $($expr:tt)+) => {
+        $crate::do_expression!($var, $($expr)+).as_mut()
+    };
+    (@prefix $var:expr, copied) => {
+        $var.copied()
+    };
+    (@prefix $var:expr, cloned) => {
+        $var.cloned()
+    };
+    (@prefix $var:expr, copied $($expr:tt)+) => {
+        $crate::do_expression!($var, $($expr)+).copied()
+    };
+    (@prefix $var:expr, cloned $($expr:tt)+) => {
+        $crate::do_expression!($var, $($expr)+).cloned()
+    };
+    (@prefix $var:expr, assume_init) => {
+        ::core::mem::MaybeUninit::assume_init_ref($var)
+    };
+    (@prefix $var:expr, assume_init $($expr:tt)+) => {
+        ::core::mem::MaybeUninit::assume_init_ref(&$crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, lock) => {{
+        let guard = ::core::result::Result::unwrap(::std::sync::Mutex::lock(&$var));
+        $crate::__private::copy(&*guard)
+    }};
+    (@prefix $var:expr, lock clone $($expr:tt)+) => {{
+        let guard = ::core::result::Result::unwrap(::std::sync::Mutex::lock(&$var));
+        ::core::clone::Clone::clone(&$crate::do_expression!((*guard), $($expr)+))
+    }};
+    (@prefix $var:expr, lock $($expr:tt)+) => {{
+        let guard = ::core::result::Result::unwrap(::std::sync::Mutex::lock(&$var));
+        $crate::__private::copy(&$crate::do_expression!((*guard), $($expr)+))
+    }};
+    (@prefix $var:expr, flatten flatten $($expr:tt)*) => {
+        ::core::option::Option::and_then(
+            $crate::do_pluck!(@prefix $var, flatten $($expr)*),
+            ::core::option::Option::as_ref,
+        )
+    };
+    (@prefix $var:expr, flatten $($expr:tt)+) => {
+        ::core::option::Option::as_ref(&$crate::do_expression!($var, $($expr)+))
+    };
+    (@prefix $var:expr, ok $($expr:tt)+) => {
+        ::core::result::Result::ok(::core::result::Result::as_ref(
+            &$crate::do_expression!($var, $($expr)+),
+        ))
+    };
+    (@prefix $var:expr, err $($expr:tt)+) => {
+        ::core::result::Result::err(::core::result::Result::as_ref(
+            &$crate::do_expression!($var, $($expr)+),
+        ))
+    };
+    (@prefix $var:expr, &mut $($expr:tt)+) => {
+        &mut $crate::do_pluck!(@prefix $var, $($expr)+)
+    };
+    (@prefix $var:expr, &&$($expr:tt)+) => {
+        &&$crate::do_pluck!(@prefix $var, $($expr)+)
+    };
+    (@prefix $var:expr, &$($expr:tt)+) => {
+        &$crate::do_pluck!(@prefix $var, $($expr)+)
+    };
+    (@prefix $var:expr, $func:ident($($args:tt)+)) => {
+        $crate::do_pluck!(@call $var, $func [] [] $($args)+)
+    };
+    (@prefix $var:expr, $($expr:tt)+) => {
+        $crate::do_expression!($var, $($expr)+)
+    };
+
+    (@call $var:expr, $func:ident [$($done:tt)*] [] , $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `,`")
+    };
+    (@call $var:expr, $func:ident [$($done:tt)*] [$($cur:tt)+] , $($rest:tt)*) => {
+        $crate::do_pluck!(@call $var, $func [$($done)* [$($cur)+]] [] $($rest)*)
+    };
+    (@call $var:expr, $func:ident [$($done:tt)*] [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@call $var, $func [$($done)*] [$($cur)* $next] $($rest)*)
+    };
+    (@call $var:expr, $func:ident [$($done:tt)*] [$($cur:tt)+]) => {
+        $crate::do_pluck!(@call $var, $func [$($done)* [$($cur)+]] [])
+    };
+    (@call $var:expr, $func:ident [$([$($arg:tt)+])+] []) => {
+        $func($($crate::do_pluck!(@binop $var, [] $($arg)+)),+)
+    };
+
+    (@binop $var:expr, [$($lhs:tt)*] .$next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)* .$next] $($rest)*)
+    };
+    (@binop $var:expr, [$($lhs:tt)*] ($($group:tt)*) $($rest:tt)*) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)* ($($group)*)] $($rest)*)
+    };
+    (@binop $var:expr, [$($lhs:tt)*] [$($group:tt)*] $($rest:tt)*) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)* [$($group)*]] $($rest)*)
+    };
+    (@binop $var:expr, [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@binop $var, [$($lhs)* $next] $($rest)*)
+    };
+    (@binop $var:expr, [$($lhs:tt)*]) => {
+        $crate::do_pluck!(@suffix $var, [] $($lhs)*)
+    };
+    (@operand $var:expr, [$($lhs:tt)+] + $($rhs:tt)+) => {
+        $crate::do_pluck!(@suffix $var, [] $($lhs)+) + $crate::do_pluck!(@suffix $var, [] $($rhs)+)
+    };
+    (@operand $var:expr, [$($lhs:tt)+] - $($rhs:tt)+) => {
+        $crate::do_pluck!(@suffix $var, [] $($lhs)+) - $crate::do_pluck!(@suffix $var, [] $($rhs)+)
+    };
+    (@operand $var:expr, [$($lhs:tt)+] * $($rhs:tt)+) => {
+        $crate::do_pluck!(@suffix $var, [] $($lhs)+) * $crate::do_pluck!(@suffix $var, [] $($rhs)+)
+    };
+    (@operand $var:expr, [$($lhs:tt)+] / $($rhs:tt)+) => {
+        $crate::do_pluck!(@suffix $var, [] $($lhs)+) / $crate::do_pluck!(@suffix $var, [] $($rhs)+)
+    };
+    (@operand $var:expr, [$($lhs:tt)*] .$next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)* .$next] $($rest)*)
+    };
+    (@operand $var:expr, [$($lhs:tt)*] as $($rest:tt)*) => {
+        $crate::do_pluck!(@cast $var, [$($lhs)* as] $($rest)*)
+    };
+    (@operand $var:expr, [$($lhs:tt)*] into $($rest:tt)*) => {
+        $crate::do_pluck!(@cast $var, [$($lhs)* into] $($rest)*)
+    };
+    (@operand $var:expr, [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)* $next] $($rest)*)
+    };
+    (@operand $var:expr, [$($lhs:tt)*]) => {
+        $crate::do_pluck!(@suffix $var, [] $($lhs)*)
+    };
+
+    (@cast $var:expr, [$($lhs:tt)+] $op:tt . $($rhs:tt)+) => {
+        $crate::do_pluck!(@cast_split $var, [$($lhs)+] $op [. $($rhs)+])
+    };
+    (@cast $var:expr, [$($lhs:tt)+] $op:tt ($($group:tt)*) $($rhs:tt)*) => {
+        $crate::do_pluck!(@cast_split $var, [$($lhs)+] $op [($($group)*) $($rhs)*])
+    };
+    (@cast $var:expr, [$($lhs:tt)+] $op:tt [$($group:tt)*] $($rhs:tt)*) => {
+        $crate::do_pluck!(@cast_split $var, [$($lhs)+] $op [[$($group)*] $($rhs)*])
+    };
+    (@cast $var:expr, [$($lhs:tt)+] $op:tt & $($rhs:tt)+) => {
+        $crate::do_pluck!(@cast_split $var, [$($lhs)+] $op [& $($rhs)+])
+    };
+    (@cast $var:expr, [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@cast $var, [$($lhs)* $next] $($rest)*)
+    };
+    (@cast $var:expr, [$($lhs:tt)*]) => {
+        $crate::do_pluck!(@suffix $var, [] $($lhs)*)
+    };
+    (@cast_split $var:expr, [$($lhs:tt)+] + [$($rhs:tt)+]) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)+] + $($rhs)+)
+    };
+    (@cast_split $var:expr, [$($lhs:tt)+] - [$($rhs:tt)+]) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)+] - $($rhs)+)
+    };
+    (@cast_split $var:expr, [$($lhs:tt)+] * [$($rhs:tt)+]) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)+] * $($rhs)+)
+    };
+    (@cast_split $var:expr, [$($lhs:tt)+] / [$($rhs:tt)+]) => {
+        $crate::do_pluck!(@operand $var, [$($lhs)+] / $($rhs)+)
+    };
+    (@cast_split $var:expr, [$($lhs:tt)+] $next:tt [$($rest:tt)+]) => {
+        $crate::do_pluck!(@cast $var, [$($lhs)+ $next] $($rest)+)
+    };
+
+    (@split $var:expr, [$($done:tt)*] [] , $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `,`")
+    };
+    (@split $var:expr, [$($done:tt)*] [$($cur:tt)+] , $($rest:tt)*) => {
+        $crate::do_pluck!(@split $var, [$($done)* [$($cur)+]] [] $($rest)*)
+    };
+    (@split $var:expr, [$($done:tt)*] [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@split $var, [$($done)*] [$($cur)* $next] $($rest)*)
+    };
+    (@split $var:expr, [] [$($cur:tt)+]) => {
+        $crate::do_pluck!(@binop $var, [] $($cur)+)
+    };
+    (@split $var:expr, [$($done:tt)*] [$($cur:tt)+]) => {
+        $crate::do_pluck!(@split $var, [$($done)* [$($cur)+]] [])
+    };
+    (@split $var:expr, [$([$($elem:tt)+])+] []) => {
+        ($($crate::do_pluck!(@binop $var, [] $($elem)+),)+)
+    };
+
+    (@default $method:ident $var:expr, [] , $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `,`")
+    };
+    (@default $method:ident $var:expr, [$($cur:tt)+] ,) => {
+        ::core::compile_error!("expected a default after `,`")
+    };
+    (@default $method:ident $var:expr, [$($cur:tt)+] , $default:expr) => {
+        $crate::do_pluck!($var, $($cur)+).$method($default)
+    };
+    (@default $method:ident $var:expr, [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@default $method $var, [$($cur)* $next] $($rest)*)
+    };
+    (@default $method:ident $var:expr, [$($cur:tt)*]) => {
+        ::core::compile_error!("expected a property and a default separated by `,`")
+    };
+
+    (@each $keyword:ident $var:expr, [$($out:tt)*] , $($rest:tt)*) => {
+        $crate::do_pluck!(@each $keyword $var, [$($out)* , $keyword] $($rest)*)
+    };
+    (@each $keyword:ident $var:expr, [$($out:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@each $keyword $var, [$($out)* $next] $($rest)*)
+    };
+    (@each $keyword:ident $var:expr, [$($out:tt)*]) => {
+        $crate::do_pluck!($var, $($out)*)
+    };
+
+    (@collect $iter:expr, [] => $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `=>`")
+    };
+    (@collect $iter:expr, [$($cur:tt)+] => $ty:ty) => {
+        ::core::iter::Iterator::collect::<$ty>(::core::iter::Iterator::map(
+            ::core::iter::IntoIterator::into_iter($iter),
+            $crate::pluck!($($cur)+),
+        ))
+    };
+    (@collect $iter:expr, [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@collect $iter, [$($cur)* $next] $($rest)*)
+    };
+    (@collect $iter:expr, [$($cur:tt)*]) => {
+        ::core::compile_error!("expected a property and a collection type separated by `=>`")
+    };
+
+    (@inspect [] => $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `=>`")
+    };
+    (@inspect [$($cur:tt)+] => $action:expr) => {
+        |value| $crate::__private::inspect($crate::do_pluck!(value, $($cur)+), &mut ($action))
+    };
+    (@inspect [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@inspect [$($cur)* $next] $($rest)*)
+    };
+    (@inspect [$($cur:tt)*]) => {
+        ::core::compile_error!("expected a property and an action separated by `=>`")
+    };
+
+    (@gate [] => $($rest:tt)*) => {
+        ::core::compile_error!("expected a condition before `=>`")
+    };
+    (@gate [$($cur:tt)+] =>) => {
+        ::core::compile_error!("expected a property after `=>`")
+    };
+    (@gate [$($cur:tt)+] => $($rest:tt)+) => {
+        |value| {
+            if $crate::__private::copy(&$crate::do_pluck!(value, $($cur)+)) {
+                ::core::option::Option::Some($crate::do_pluck!(@each clone value, [clone] $($rest)+))
+            } else {
+                ::core::option::Option::None
+            }
+        }
+    };
+    (@gate [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@gate [$($cur)* $next] $($rest)*)
+    };
+    (@gate [$($cur:tt)*]) => {
+        ::core::compile_error!("expected a condition and a property separated by `=>`")
+    };
+
+    (@halves [] , $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `,`")
+    };
+    (@halves [$($cur:tt)+] , $mid:expr) => {
+        |value| {
+            let whole = $crate::do_pluck!(value, &$($cur)+);
+            let mid = $mid;
+            (&whole[..mid], &whole[mid..])
+        }
+    };
+    (@halves [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@halves [$($cur)* $next] $($rest)*)
+    };
+    (@halves [$($cur:tt)*]) => {
+        ::core::compile_error!("expected a property and an index separated by `,`")
+    };
+
+    (@keyed $kind:ident $iter:expr, [] => $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `=>`")
+    };
+    (@keyed $kind:ident $iter:expr, [$($cur:tt)+] => $($map:ident)::+) => {
+        $crate::do_pluck!(@keyed_into $kind [$($map)::+] $iter, $($cur)+)
+    };
+    (@keyed $kind:ident $iter:expr, [$($cur:tt)+] => $($rest:tt)*) => {
+        ::core::compile_error!("expected a map type like `BTreeMap` after `=>`")
+    };
+    (@keyed $kind:ident $iter:expr, [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@keyed $kind $iter, [$($cur)* $next] $($rest)*)
+    };
+    (@keyed $kind:ident $iter:expr, [$($cur:tt)+]) => {
+        $crate::do_pluck!(@keyed_into $kind [::std::collections::HashMap] $iter, $($cur)+)
+    };
+
+    (@keyed_into group [$($map:tt)+] $iter:expr, $($expr:tt)+) => {{
+        let mut groups = $($map)+::<_, ::std::vec::Vec<_>>::new();
+        for value in ::core::iter::IntoIterator::into_iter($iter) {
+            let key = $crate::do_pluck!(@each clone value, [clone] $($expr)+);
+            groups.entry(key).or_default().push(value);
+        }
+        groups
+    }};
+    (@keyed_into insert [$($map:tt)+] $iter:expr, $($expr:tt)+) => {{
+        let mut map = $($map)+::new();
+        for value in ::core::iter::IntoIterator::into_iter($iter) {
+            let key = $crate::do_pluck!(@each clone value, [clone] $($expr)+);
+            map.insert(key, value);
+        }
+        map
+    }};
+
+    (@scan $state:ident $var:ident, += $($expr:tt)+) => {
+        *$state += $crate::do_pluck!($var, $($expr)+)
+    };
+    (@scan $state:ident $var:ident, -= $($expr:tt)+) => {
+        *$state -= $crate::do_pluck!($var, $($expr)+)
+    };
+    (@scan $state:ident $var:ident, *= $($expr:tt)+) => {
+        *$state *= $crate::do_pluck!($var, $($expr)+)
+    };
+    (@scan $state:ident $var:ident, /= $($expr:tt)+) => {
+        *$state /= $crate::do_pluck!($var, $($expr)+)
+    };
+    (@scan $state:ident $var:ident, $($expr:tt)*) => {
+        ::core::compile_error!("expected `+=`, `-=`, `*=` or `/=` followed by a property")
+    };
+
+    (@combine $acc:ident $var:ident, + $($expr:tt)+) => {
+        $acc + $crate::do_pluck!($var, $($expr)+)
+    };
+    (@combine $acc:ident $var:ident, * $($expr:tt)+) => {
+        $acc * $crate::do_pluck!($var, $($expr)+)
+    };
+    (@combine $acc:ident $var:ident, min $($expr:tt)+) => {
+        $crate::do_pluck!($var, $($expr)+).min($acc)
+    };
+    (@combine $acc:ident $var:ident, max $($expr:tt)+) => {
+        $crate::do_pluck!($var, $($expr)+).max($acc)
+    };
+    (@combine $acc:ident $var:ident, $($expr:tt)*) => {
+        ::core::compile_error!("expected `+`, `*`, `min` or `max` followed by a property")
+    };
+
+    (@ok $var:expr, $($expr:tt)+) => {
+        ::core::result::Result::Ok($crate::do_pluck!(@split $var, [] [] $($expr)+))
+    };
+
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] ? $($rest:tt)*) => {
+        ::core::option::Option::Some($crate::do_pluck!(@typed {$($ty)*} $crate::do_pluck!(@split $var, [] [] $($all)+)))
+    };
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] . $method:ident ($($args:tt)*) $($rest:tt)*) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [$($stack)*] $($rest)*)
+    };
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] . $method:ident :: $($rest:tt)*) => {
+        $crate::do_pluck!(@try_turbofish $var, {$($ty)*} [$($all)+] [$($stack)*] $($rest)*)
+    };
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] | $($rest:tt)*) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [$($stack)*])
+    };
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] || $($rest:tt)*) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [$($stack)*])
+    };
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] ($($inner:tt)*) $($rest:tt)*) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [[$($rest)*] $($stack)*] $($inner)*)
+    };
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] [$($inner:tt)*] $($rest:tt)*) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [[$($rest)*] $($stack)*] $($inner)*)
+    };
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [$($stack)*] $($rest)*)
+    };
+    (@try $var:expr, {$($ty:tt)*} [$($all:tt)+] [[$($outer:tt)*] $($stack:tt)*]) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [$($stack)*] $($outer)*)
+    };
+    (@try $var:expr, {} [$($all:tt)+] []) => {
+        $crate::do_pluck!(@split $var, [] [] $($all)+)
+    };
+    (@try $var:expr, {$ty:ty} [$($all:tt)+] []) => {
+        $crate::do_pluck!(@typed {$ty} $crate::do_pluck!(@access $var, $($all)+))
+    };
+    (@try_turbofish $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] ($($args:tt)*) $($rest:tt)*) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [$($stack)*] $($rest)*)
+    };
+    (@try_turbofish $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@try_turbofish $var, {$($ty)*} [$($all)+] [$($stack)*] $($rest)*)
+    };
+    (@try_turbofish $var:expr, {$($ty:tt)*} [$($all:tt)+] [$($stack:tt)*]) => {
+        $crate::do_pluck!(@try $var, {$($ty)*} [$($all)+] [$($stack)*])
+    };
+
+    (@typed {} $value:expr) => {
+        $value
+    };
+    (@typed {$ty:ty} $value:expr) => {{
+        let out: $ty = $value;
+        out
+    }};
+
+    (@field $kind:tt $var:expr, [$($path:tt)+] 0 $($rest:tt)*) => {
+        $crate::do_pluck!(@bind $kind $var, [$($path)+(inner, ..)] inner $($rest)*)
+    };
+    (@field $kind:tt $var:expr, [$($path:tt)+] 1 $($rest:tt)*) => {
+        $crate::do_pluck!(@bind $kind $var, [$($path)+(_, inner, ..)] inner $($rest)*)
+    };
+    (@field $kind:tt $var:expr, [$($path:tt)+] 2 $($rest:tt)*) => {
+        $crate::do_pluck!(@bind $kind $var, [$($path)+(_, _, inner, ..)] inner $($rest)*)
+    };
+    (@field $kind:tt $var:expr, [$($path:tt)+] 3 $($rest:tt)*) => {
+        $crate::do_pluck!(@bind $kind $var, [$($path)+(_, _, _, inner, ..)] inner $($rest)*)
+    };
+    (@field $kind:tt $var:expr, [$($path:tt)+] 4 $($rest:tt)*) => {
+        $crate::do_pluck!(@bind $kind $var, [$($path)+(_, _, _, _, inner, ..)] inner $($rest)*)
+    };
+    (@field $kind:tt $var:expr, [$($path:tt)+] 5 $($rest:tt)*) => {
+        $crate::do_pluck!(@bind $kind $var, [$($path)+(_, _, _, _, _, inner, ..)] inner $($rest)*)
+    };
+    (@field $kind:tt $var:expr, [$($path:tt)+] $idx:tt $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected a field from 0 to 5, found `",
+            ::core::stringify!($idx),
+            "`",
+        ))
+    };
+    (@bind if $var:expr, [$($pat:tt)+] $inner:ident $($rest:tt)*) => {
+        if let $($pat)+ = $var {
+            ::core::option::Option::Some($crate::do_expression!($inner, $($rest)*))
+        } else {
+            ::core::option::Option::None
+        }
+    };
+    (@bind let $var:expr, [$($pat:tt)+] $inner:ident $($rest:tt)*) => {{
+        let $($pat)+ = $var;
+        $crate::do_expression!($inner, $($rest)*)
+    }};
+
+    (@ascribe $var:expr, [] : $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `:`")
+    };
+    (@ascribe $var:expr, [$($cur:tt)+] :) => {
+        ::core::compile_error!("expected a type after `:`")
+    };
+    (@ascribe $var:expr, [? $($cur:tt)*] : $ty:ty) => {{
+        let out: $ty = $crate::do_pluck!(@access $var, ? $($cur)*);
+        out
+    }};
+    (@ascribe $var:expr, [$($cur:tt)+] : $ty:ty) => {
+        $crate::do_pluck!(@try $var, {$ty} [$($cur)+] [] $($cur)+)
+    };
+    (@ascribe $var:expr, [] => $($rest:tt)*) => {
+        ::core::compile_error!("expected a key before `=>`")
+    };
+    (@ascribe $var:expr, [$($cur:tt)+] =>) => {
+        ::core::compile_error!("expected a value after `=>`")
+    };
+    (@ascribe $var:expr, [$($cur:tt)+] => $($rest:tt)+) => {
+        (
+            $crate::do_pluck!(@each clone $var, [clone] $($cur)+),
+            $crate::do_pluck!($var, $($rest)+),
+        )
+    };
+    (@ascribe $var:expr, [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@ascribe $var, [$($cur)* $next] $($rest)*)
+    };
+    (@ascribe $var:expr, [$($cur:tt)+]) => {
+        $crate::do_pluck!(@access $var, $($cur)+)
+    };
+
+    (@access $var:expr, ?) => {
+        ::core::compile_error!("expected a property after `?`")
+    };
+    (@access $var:expr, ? $($expr:tt)+) => {
+        $var.as_ref().map(|inner| $crate::do_pluck!(inner, $($expr)+))
+    };
+    (@access $var:expr, let $first:ident $(:: $path:ident)* (.$idx:tt $($rest:tt)*)) => {
+        $crate::do_pluck!(@field let $var, [$first $(:: $path)*] $idx $($rest)*)
+    };
+    (@access $var:expr, $first:ident $(:: $path:ident)+ (.$idx:tt $($rest:tt)*)) => {
+        $crate::do_pluck!(@field if $var, [$first $(:: $path)+] $idx $($rest)*)
+    };
+    (@access $var:expr, $first:ident $(:: $path:ident)+ { $field:ident $(, ..)? }) => {
+        $crate::do_pluck!(@bind if $var, [$first $(:: $path)+ { $field: inner, .. }] inner)
+    };
+    (@access $var:expr, [$($inner:tt)+]) => {
+        $crate::do_pluck!(@array array $var, [] [] $($inner)+)
+    };
+    (@access $var:expr, vec![]) => {
+        ::core::compile_error!("expected a property inside `vec![]`")
+    };
+    (@access $var:expr, vec![$($inner:tt)+]) => {
+        $crate::do_pluck!(@array vec $var, [] [] $($inner)+)
+    };
+    (@access $var:expr, $($expr:tt)+) => {
+        $crate::do_pluck!(@try $var, {} [$($expr)+] [] $($expr)+)
+    };
+
+    (@array $kind:ident $var:expr, [$($done:tt)*] [] , $($rest:tt)*) => {
+        ::core::compile_error!("expected a property before `,`")
+    };
+    (@array $kind:ident $var:expr, [$($done:tt)*] [$($cur:tt)+] , $($rest:tt)*) => {
+        $crate::do_pluck!(@array $kind $var, [$($done)* [$($cur)+]] [] $($rest)*)
+    };
+    (@array $kind:ident $var:expr, [$($done:tt)*] [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::do_pluck!(@array $kind $var, [$($done)*] [$($cur)* $next] $($rest)*)
+    };
+    (@array array $var:expr, [] [$($cur:tt)+]) => {
+        $crate::do_pluck!(@try $var, {} [[$($cur)+]] [] [$($cur)+])
+    };
+    (@array $kind:ident $var:expr, [$($done:tt)*] [$($cur:tt)+]) => {
+        $crate::do_pluck!(@array $kind $var, [$($done)* [$($cur)+]] [])
+    };
+    (@array array $var:expr, [$([$($elem:tt)+])+] []) => {
+        [$($crate::__private::copy(&$crate::do_pluck!($var, $($elem)+))),+]
+    };
+    (@array vec $var:expr, [$([$($elem:tt)+])+] []) => {
+        $crate::__private::vec![$($crate::__private::copy(&$crate::do_pluck!($var, $($elem)+))),+]
+    };
+
+    ($var:expr, unsafe) => {
+        ::core::compile_error!("expected a property after `unsafe`")
+    };
+    ($var:expr, unsafe $($expr:tt)+) => {
+        unsafe { $crate::do_pluck!($var, $($expr)+) }
+    };
+    ($var:expr, $($expr:tt)+) => {
+        $crate::do_pluck!(@ascribe $var, [] $($expr)+)
+    };
+}
+
+/// Create a lambda that extracts the provided property from the argument.
+///
 /// See [crate level documentation](crate) for detailed usage.
 #[macro_export]
 macro_rules! pluck {
-    (&mut $($expr:tt)+) => {
-        |value| &mut $crate::do_expression!(value, $( $expr )+)
+    () => {
+        ::core::compile_error!("expected a property to pluck")
+    };
+    (move) => {
+        ::core::compile_error!("expected a property to pluck after `move`")
+    };
+    (move $($expr:tt)+) => {
+        move |value| $crate::do_pluck!(value, $( $expr )+)
+    };
+    ($($expr:tt)+) => {
+        |value| $crate::do_pluck!(value, $( $expr )+)
+    };
+}
+
+/// Create a lambda that extracts the provided property from the argument,
+/// propagating errors from [`Result`] properties followed by `?`.
+///
+/// See [crate level documentation](crate#optional-chaining) for detailed usage.
+#[macro_export]
+macro_rules! try_pluck {
+    () => {
+        ::core::compile_error!("expected a property to pluck")
+    };
+    ($($expr:tt)+) => {
+        |value| $crate::do_pluck!(@ok value, $( $expr )+)
+    };
+}
+
+/// Create a lambda that extracts an owned clone of the provided property,
+/// suitable for [`sort_by_key`](slice::sort_by_key) and
+/// [`min_by_key`](Iterator::min_by_key).
+///
+/// See [crate level documentation](crate#sorting) for detailed usage.
+#[macro_export]
+macro_rules! sort_key {
+    () => {
+        ::core::compile_error!("expected a property to pluck")
+    };
+    ($($expr:tt)+) => {
+        |value| $crate::do_pluck!(@each clone value, [clone] $( $expr )+)
+    };
+}
+
+/// Create a lambda that extracts an owned clone of the provided property,
+/// suitable for [`dedup_by_key`](Vec::dedup_by_key).
+///
+/// See [crate level documentation](crate#sorting) for detailed usage.
+#[macro_export]
+macro_rules! dedup_key {
+    () => {
+        ::core::compile_error!("expected a property to pluck")
+    };
+    ($($expr:tt)+) => {
+        |value| $crate::do_pluck!(@each clone value, [clone] $( $expr )+)
+    };
+}
+
+/// Create a comparator that orders its arguments by the provided property,
+/// suitable for [`sort_by`](slice::sort_by).
+///
+/// See [crate level documentation](crate#sorting) for detailed usage.
+#[macro_export]
+macro_rules! cmp_by {
+    () => {
+        ::core::compile_error!("expected a property to compare by")
+    };
+    (rev $($expr:tt)+) => {
+        |left, right| ::core::cmp::Ord::cmp(
+            &$crate::do_pluck!(right, $( $expr )+),
+            &$crate::do_pluck!(left, $( $expr )+),
+        )
+    };
+    ($($expr:tt)+) => {
+        |left, right| ::core::cmp::Ord::cmp(
+            &$crate::do_pluck!(left, $( $expr )+),
+            &$crate::do_pluck!(right, $( $expr )+),
+        )
+    };
+}
+
+/// Define an `#[inline(always)]` function that extracts the provided property
+/// from the argument.
+///
+/// See [crate level documentation](crate#named-functions) for detailed usage.
+#[macro_export]
+macro_rules! pluck_fn {
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($arg:ty) -> $ret:ty = $($expr:tt)+) => {
+        #[inline(always)]
+        $(#[$attr])*
+        $vis fn $name(value: $arg) -> $ret {
+            $crate::do_pluck!(value, $( $expr )+)
+        }
+    };
+}
+
+/// Create a lambda that extracts the provided [`Option`] property from the
+/// argument, falling back to the provided default.
+///
+/// See [crate level documentation](crate#defaults) for detailed usage.
+#[macro_export]
+macro_rules! pluck_or {
+    ($($expr:tt)+) => {
+        |value| $crate::do_pluck!(@default unwrap_or value, [] $( $expr )+)
+    };
+}
+
+/// Create a lambda that extracts the provided [`Option`] property from the
+/// argument, calling the provided closure for a default.
+///
+/// See [crate level documentation](crate#defaults) for detailed usage.
+#[macro_export]
+macro_rules! pluck_or_else {
+    ($($expr:tt)+) => {
+        |value| $crate::do_pluck!(@default unwrap_or_else value, [] $( $expr )+)
+    };
+}
+
+/// Create a lambda that returns a clone of the property following `=>` in
+/// [`Some`] when the provided [`bool`] property is `true`, and [`None`]
+/// otherwise.
+///
+/// See [crate level documentation](crate#conditions) for detailed usage.
+#[macro_export]
+macro_rules! pluck_if {
+    ($($expr:tt)+) => {
+        $crate::do_pluck!(@gate [] $( $expr )+)
+    };
+}
+
+/// Create a lambda that splits the provided property at the index following
+/// `,`, returning references to both halves.
+///
+/// See [crate level documentation](crate#index-type) for detailed usage.
+#[macro_export]
+macro_rules! split {
+    ($($expr:tt)+) => {
+        $crate::do_pluck!(@halves [] $( $expr )+)
+    };
+}
+
+/// Pluck the provided property from every item of an iterable, collecting
+/// the results into the collection type following `=>`.
+///
+/// See [crate level documentation](crate#collecting) for detailed usage.
+#[macro_export]
+macro_rules! pluck_collect {
+    ($iter:expr, $($expr:tt)+) => {
+        $crate::do_pluck!(@collect $iter, [] $( $expr )+)
+    };
+}
+
+/// Sum the provided property across every item of an iterable.
+///
+/// See [crate level documentation](crate#aggregating) for detailed usage.
+#[macro_export]
+macro_rules! pluck_sum {
+    ($iter:expr, $($expr:tt)+) => {
+        $crate::__private::sum(::core::iter::Iterator::map(
+            ::core::iter::IntoIterator::into_iter($iter),
+            $crate::pluck!($( $expr )+),
+        ))
+    };
+}
+
+/// Multiply the provided property across every item of an iterable.
+///
+/// See [crate level documentation](crate#aggregating) for detailed usage.
+#[macro_export]
+macro_rules! pluck_product {
+    ($iter:expr, $($expr:tt)+) => {
+        $crate::__private::product(::core::iter::Iterator::map(
+            ::core::iter::IntoIterator::into_iter($iter),
+            $crate::pluck!($( $expr )+),
+        ))
+    };
+}
+
+/// Create a lambda for [`scan`](Iterator::scan) that updates the state with
+/// the provided property using `+=`, `-=`, `*=` or `/=`.
+///
+/// See [crate level documentation](crate#accumulating) for detailed usage.
+#[macro_export]
+macro_rules! pluck_scan {
+    ($($expr:tt)+) => {
+        |state, value| {
+            $crate::do_pluck!(@scan state value, $( $expr )+);
+            ::core::option::Option::Some(::core::clone::Clone::clone(&*state))
+        }
+    };
+}
+
+/// Create a lambda for [`fold`](Iterator::fold) that combines the
+/// accumulator with the provided property using `+`, `*`, `min` or `max`.
+///
+/// See [crate level documentation](crate#accumulating) for detailed usage.
+#[macro_export]
+macro_rules! combine {
+    ($($expr:tt)+) => {
+        |acc, value| $crate::do_pluck!(@combine acc value, $( $expr )+)
+    };
+}
+
+/// Group the items of an iterable into a `HashMap`, or the map type following
+/// `=>`, keyed by a clone of the provided property.
+///
+/// See [crate level documentation](crate#grouping) for detailed usage.
+#[macro_export]
+macro_rules! pluck_group_by {
+    ($iter:expr, $($expr:tt)+) => {
+        $crate::do_pluck!(@keyed group $iter, [] $( $expr )+)
     };
-    (&$($expr:tt)+) => {
-        |value| & $crate::do_expression!(value, $( $expr )+)
+}
+
+/// Collect the items of an iterable into a `HashMap`, or the map type following
+/// `=>`, keyed by a clone of the provided property, keeping the last item for
+/// each key.
+///
+/// See [crate level documentation](crate#grouping) for detailed usage.
+#[macro_export]
+macro_rules! map_by_key {
+    ($iter:expr, $($expr:tt)+) => {
+        $crate::do_pluck!(@keyed insert $iter, [] $( $expr )+)
     };
+}
+
+/// Create a lambda that plucks the provided property and passes it to the
+/// action following `=>`, returning `()`.
+///
+/// See [crate level documentation](crate#inspecting) for detailed usage.
+#[macro_export]
+macro_rules! inspect_pluck {
     ($($expr:tt)+) => {
-        |value| $crate::do_expression!(value, $( $expr )+)
+        $crate::do_pluck!(@inspect [] $( $expr )+)
     };
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Extension trait providing [`pluck`](PluckExt::pluck) on every [`Iterator`].
+///
+/// ```
+/// # use pluck::*;
+/// let list = [(0, "a"), (1, "b"), (2, "c")];
+///
+/// let second = list.iter().pluck(pluck!(.1)).collect::<Vec<_>>();
+/// assert_eq!(second, &["a", "b", "c"]);
+/// ```
+pub trait PluckExt: Iterator {
+    /// Pluck a property from each element. Equivalent to [`Iterator::map`].
+    fn pluck<B, F>(self, f: F) -> core::iter::Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> B,
+    {
+        self.map(f)
+    }
+}
+
+impl<I: Iterator> PluckExt for I {}
+
+#[cfg(test)]
+#[allow(clippy::useless_vec)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ref_to_triple_deref() {
+        let list = vec![&&&0, &&&1, &&&2];
+        let derefed = list.into_iter().map(pluck!(&***)).collect::<Vec<_>>();
+        assert_eq!(derefed, &[&0, &1, &2]);
+    }
+
+    #[test]
+    fn mut_ref_deref() {
+        let mut list = vec![0, 1, 2];
+        let derefed = list.iter_mut().map(pluck!(&mut *)).collect::<Vec<_>>();
+        assert_eq!(derefed, &[&0, &1, &2]);
+    }
+
+    #[test]
+    fn deref_index() {
+        let mut list = vec![[&0], [&1], [&2]];
+        let derefed = list.iter_mut().map(pluck!(*[0])).collect::<Vec<_>>();
+        assert_eq!(derefed, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn nested_index() {
+        let mut list = vec![[[0]], [[1]], [[2]]];
+        let derefed = list.iter_mut().map(pluck!([0][0])).collect::<Vec<_>>();
+        assert_eq!(derefed, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn nested_property() {
+        struct Person { address: Address }
+        struct Address { street: &'static str }
+
+        let mut list = [Person { address: Address { street: "foo" } }];
+        let streets = list.iter_mut().map(pluck!(.address.street)).collect::<Vec<_>>();
+        assert_eq!(streets, &["foo"]);
+    }
+
+    #[test]
+    fn index_deref() {
+        let mut list = vec![[&[0]], [&[1]], [&[2]]];
+        let derefed = list.iter_mut().map(pluck!((*[0])[0])).collect::<Vec<_>>();
+        assert_eq!(derefed, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn index_deref_property() {
+        struct Person { name: &'static str }
+        let mut list = vec![[&Person { name: "Alice" }]];
+        let derefed = list.iter_mut().map(pluck!((*[0]).name)).collect::<Vec<_>>();
+        assert_eq!(derefed, &["Alice"]);
+    }
+
+    #[test]
+    fn method_call() {
+        let list = ["a", "bb", "ccc"];
+        let lengths = list.iter().map(pluck!(.len())).collect::<Vec<_>>();
+        assert_eq!(lengths, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn property_method_call() {
+        struct Person { name: &'static str }
+        let list = [Person { name: " Alice " }];
+        let names = list.iter().map(pluck!(.name.trim())).collect::<Vec<_>>();
+        assert_eq!(names, &["Alice"]);
+    }
+
+    #[test]
+    fn index_deref_method_call() {
+        let mut list = vec![[&0], [&1], [&2]];
+        let strings = list.iter_mut().map(pluck!((*[0]).to_string())).collect::<Vec<_>>();
+        assert_eq!(strings, &["0", "1", "2"]);
+    }
+
+    #[test]
+    fn method_call_with_arguments() {
+        let list = ["a,b", "c,d"];
+        let split = list.iter().map(pluck!(.split_once(','))).collect::<Vec<_>>();
+        assert_eq!(split, &[Some(("a", "b")), Some(("c", "d"))]);
+    }
+
+    #[test]
+    fn method_call_with_nested_arguments() {
+        let list = ["abc", "def"];
+        let trimmed = list.iter().map(pluck!(.trim_matches(|c| (c == 'a') || (c == 'f')))).collect::<Vec<_>>();
+        assert_eq!(trimmed, &["bc", "de"]);
+    }
+
+    #[test]
+    fn method_call_captures_local() {
+        let list = [[0, 1], [2, 3]];
+        let idx = 1;
+        let second = list.iter().map(pluck!(.get(idx))).collect::<Vec<_>>();
+        assert_eq!(second, &[Some(&1), Some(&3)]);
+    }
+
+    #[test]
+    fn method_call_captures_local_by_reference() {
+        use std::collections::HashMap;
+
+        let maps = vec![HashMap::from([("a".to_string(), 0)]), HashMap::new()];
+        let key = "a".to_string();
+        let values = maps.iter().map(pluck!(.get(&key))).collect::<Vec<_>>();
+        assert_eq!(values, &[Some(&0), None]);
+        assert_eq!(key, "a");
+    }
+
+    #[test]
+    fn clone_property() {
+        struct Person { name: String }
+        let list = [Person { name: "Alice".to_string() }];
+        let names = list.iter().map(pluck!(clone .name)).collect::<Vec<String>>();
+        assert_eq!(names, &["Alice"]);
+    }
+
+    #[test]
+    fn clone_index() {
+        let list = vec![vec!["a".to_string()], vec!["b".to_string()]];
+        let first = list.iter().map(pluck!(clone [0])).collect::<Vec<String>>();
+        assert_eq!(first, &["a", "b"]);
+    }
+
+    #[test]
+    fn clone_deref() {
+        let a = "a".to_string();
+        let b = "b".to_string();
+        let list = vec![&a, &b];
+        let cloned = list.into_iter().map(pluck!(clone *)).collect::<Vec<String>>();
+        assert_eq!(cloned, &["a", "b"]);
+    }
+
+    #[test]
+    fn copy_tuple_index() {
+        let list = [(0, "a"), (1, "b"), (2, "c")];
+        let first = list.iter().map(pluck!(copy .0)).collect::<Vec<i32>>();
+        assert_eq!(first, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn copy_reference() {
+        let list = [(&0, "a"), (&1, "b")];
+        let first = list.iter().map(pluck!(copy .0)).collect::<Vec<&i32>>();
+        assert_eq!(first, &[&0, &1]);
+    }
+
+    #[test]
+    fn copy_deref() {
+        let list = vec![&&0, &&1];
+        let copied = list.into_iter().map(pluck!(copy **)).collect::<Vec<i32>>();
+        assert_eq!(copied, &[0, 1]);
+    }
+
+    #[test]
+    fn into_numeric() {
+        let list = [(0u8, "a"), (1u8, "b")];
+        let wide = list.iter().map(pluck!(.0 into u64)).collect::<Vec<u64>>();
+        assert_eq!(wide, &[0, 1]);
+    }
+
+    #[test]
+    fn into_string() {
+        struct Person { name: &'static str }
+        let list = [Person { name: "Alice" }];
+        let names = list.iter().map(pluck!(.name into String)).collect::<Vec<String>>();
+        assert_eq!(names, &["Alice"]);
+    }
+
+    #[test]
+    fn into_after_method() {
+        let list = [" a ", " b "];
+        let trimmed = list.iter().map(pluck!(.trim() into String)).collect::<Vec<String>>();
+        assert_eq!(trimmed, &["a", "b"]);
+    }
+
+    #[test]
+    fn into_method_is_not_conversion() {
+        let list = [(0u8,), (1u8,)];
+        let wide = list.iter().map(pluck!(.0.into())).collect::<Vec<u64>>();
+        assert_eq!(wide, &[0, 1]);
+    }
+
+    #[test]
+    fn cast_tuple_index() {
+        let list = [(1, "a"), (2, "b")];
+        let floats = list.iter().map(pluck!(.0 as f64)).collect::<Vec<f64>>();
+        assert_eq!(floats, &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn cast_index() {
+        let list = [[1u8], [2u8]];
+        let sizes = list.iter().map(pluck!([0] as usize)).collect::<Vec<usize>>();
+        assert_eq!(sizes, &[1, 2]);
+    }
+
+    #[test]
+    fn cast_applies_to_whole_value() {
+        let list = vec![&-1i32, &2];
+        let casts = list.into_iter().map(pluck!(* as u8)).collect::<Vec<u8>>();
+        assert_eq!(casts, &[255, 2]);
+    }
+
+    #[test]
+    fn tuple_of_values_and_references() {
+        let list = [(0, "a"), (1, "b")];
+        let pairs = list.iter().map(pluck!(&.0, .1)).collect::<Vec<(&i32, &str)>>();
+        assert_eq!(pairs, &[(&0, "a"), (&1, "b")]);
+    }
+
+    #[test]
+    fn tuple_of_four() {
+        let list = [[[0, 1], [2, 3]]];
+        let all = list.iter().map(pluck!([0][0], [0][1], &[1][0], [1][1] as u8)).collect::<Vec<_>>();
+        assert_eq!(all, &[(0, 1, &2, 3u8)]);
+    }
+
+    #[test]
+    fn tuple_respects_groups() {
+        let list = vec![[&(0, 1)], [&(2, 3)]];
+        let pairs = list.iter().map(pluck!((*[0]).1, .len())).collect::<Vec<_>>();
+        assert_eq!(pairs, &[(1, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn tuple_of_one() {
+        let list = [(0, "a"), (1, "b")];
+        let single = list.iter().map(pluck!(.1,)).collect::<Vec<_>>();
+        assert_eq!(single, &[("a",), ("b",)]);
+    }
+
+    #[test]
+    fn optional_chain() {
+        struct A { b: Option<B> }
+        struct B { c: Option<C> }
+        struct C { value: i32 }
+
+        let list = [
+            A { b: Some(B { c: Some(C { value: 0 }) }) },
+            A { b: Some(B { c: None }) },
+            A { b: None },
+        ];
+        let values = list.iter().map(pluck!(.b?.c?.value)).collect::<Vec<_>>();
+        assert_eq!(values, &[Some(0), None, None]);
+    }
+
+    #[test]
+    fn optional_chain_reference() {
+        struct Person { name: String, parent: Option<Box<Person>> }
+        let list = [
+            Person { name: "Bob".to_string(), parent: Some(Box::new(Person { name: "Alice".to_string(), parent: None })) },
+            Person { name: "Alice".to_string(), parent: None },
+        ];
+        let parents = list.iter().map(pluck!(&.parent?.name)).collect::<Vec<Option<&String>>>();
+        assert_eq!(parents, &[Some(&"Alice".to_string()), None]);
+    }
+
+    #[test]
+    fn optional_chain_method() {
+        let list = [vec![(0, "a")], vec![]];
+        let firsts = list.iter().map(pluck!(.first()?.1)).collect::<Vec<_>>();
+        assert_eq!(firsts, &[Some("a"), None]);
+    }
+
+    #[test]
+    fn optional_chain_in_group() {
+        let list = vec![[Some(&0)], [None]];
+        let values = list.iter().map(pluck!((*[0]?) as u8)).collect::<Vec<_>>();
+        assert_eq!(values, &[Some(0), None]);
+    }
+
+    #[test]
+    fn optional_chain_tuple() {
+        let list = [(Some(0), "a"), (None, "b")];
+        let values = list.iter().map(pluck!(.0?, .1)).collect::<Vec<_>>();
+        assert_eq!(values, &[Some((0, "a")), None]);
+    }
+
+    #[test]
+    fn or_map_get() {
+        use std::collections::HashMap;
+
+        let maps = vec![HashMap::from([("a", 1)]), HashMap::new()];
+        let values = maps.iter().map(pluck_or!(.get("a"), &0)).collect::<Vec<_>>();
+        assert_eq!(values, &[&1, &0]);
+    }
+
+    #[test]
+    fn or_slice_get() {
+        let list = [vec![0, 1], vec![2]];
+        let i = 1;
+        let second = list.iter().map(pluck_or!(.get(i).copied(), -1)).collect::<Vec<_>>();
+        assert_eq!(second, &[1, -1]);
+    }
+
+    #[test]
+    fn or_expression_default() {
+        let list = [vec![0, 1], vec![]];
+        let fallback = vec![5, 6];
+        let firsts = list.iter().map(pluck_or!(.first().copied(), fallback.iter().sum())).collect::<Vec<i32>>();
+        assert_eq!(firsts, &[0, 11]);
+    }
+
+    #[test]
+    fn named_function() {
+        struct Person { name: &'static str }
+        pluck_fn!(fn name(&Person) -> &'static str = .name);
+
+        fn assert_copy<T: Copy + Clone>(value: T) -> T {
+            value
+        }
+
+        let list = [Person { name: "Alice" }, Person { name: "Bob" }];
+        let f = assert_copy(name);
+        let names = list.iter().map(f).collect::<Vec<_>>();
+        let first = list.iter().map(f).next();
+        assert_eq!(names, &["Alice", "Bob"]);
+        assert_eq!(first, Some("Alice"));
+        assert_eq!(f(&list[1]), "Bob");
+    }
+
+    #[test]
+    fn named_function_stored() {
+        struct Extractor { f: fn(&(i32, &'static str)) -> &'static str }
+        pluck_fn!(fn second(&(i32, &'static str)) -> &'static str = .1);
+
+        let extractor = Extractor { f: second };
+        assert_eq!((extractor.f)(&(0, "a")), "a");
+    }
+
+    #[test]
+    fn named_function_elided_lifetime() {
+        pluck_fn!(fn first(&[String]) -> &String = &[0]);
+        let list = [vec!["a".to_string()], vec!["b".to_string()]];
+        let firsts = list.iter().map(|v| first(v)).collect::<Vec<_>>();
+        assert_eq!(firsts, &["a", "b"]);
+    }
+
+    #[test]
+    fn outer_value_in_index() {
+        let list = [[0, 1], [2, 3]];
+        let value = 1;
+        let second = list.iter().map(pluck!([value])).collect::<Vec<_>>();
+        assert_eq!(second, &[1, 3]);
+    }
+
+    #[test]
+    fn outer_value_in_method_argument() {
+        let list = [[0, 1], [2, 3]];
+        let value = 0;
+        let first = list.iter().map(pluck!(.get(value))).collect::<Vec<_>>();
+        assert_eq!(first, &[Some(&0), Some(&2)]);
+    }
+
+    #[test]
+    fn nested_tuple_index_zero() {
+        let list = [((0, 1), 2), ((3, 4), 5)];
+        let nested = list.iter().map(pluck!(.0.0)).collect::<Vec<_>>();
+        assert_eq!(nested, &[0, 3]);
+    }
+
+    #[test]
+    fn nested_tuple_index_nonzero() {
+        let list = [(0, (1, 2, 3)), (4, (5, 6, 7))];
+        let nested = list.iter().map(pluck!(.1.2)).collect::<Vec<_>>();
+        assert_eq!(nested, &[3, 7]);
+    }
+
+    #[test]
+    fn deeply_nested_tuple_index() {
+        let list = [((0, (1, 2)), 3), ((4, (5, 6)), 7)];
+        let nested = list.iter().map(pluck!(.0.1.0)).collect::<Vec<_>>();
+        assert_eq!(nested, &[1, 5]);
+
+        let nested_ref = list.iter().map(pluck!(&.0.1.0)).collect::<Vec<_>>();
+        assert_eq!(nested_ref, &[&1, &5]);
+    }
+
+    #[test]
+    fn spaced_nested_tuple_index() {
+        let list = [((0, 1), 2), ((3, 4), 5)];
+        let nested = list.iter().map(pluck!(.0 .1)).collect::<Vec<_>>();
+        assert_eq!(nested, &[1, 4]);
+    }
+
+    #[test]
+    fn multi_digit_tuple_index() {
+        let list = [(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, "twelve")];
+        let last = list.iter().map(pluck!(.12)).collect::<Vec<_>>();
+        assert_eq!(last, &["twelve"]);
+
+        let tenth = list.iter().map(pluck!(&.10)).collect::<Vec<_>>();
+        assert_eq!(tenth, &[&10]);
+    }
+
+    #[test]
+    fn multi_digit_nested_tuple_index() {
+        let list = [(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, (11, 12))];
+        let nested = list.iter().map(pluck!(.11.1)).collect::<Vec<_>>();
+        assert_eq!(nested, &[12]);
+    }
+
+    #[test]
+    fn iterator_extension() {
+        let list = [[0, 1], [2, 3]];
+        let pairs = list.iter().pluck(pluck!([1], &[0])).collect::<Vec<_>>();
+        assert_eq!(pairs, &[(1, &0), (3, &2)]);
+    }
+
+    #[test]
+    fn iterator_extension_chains() {
+        let list = [("a", 1), ("bb", 2), ("ccc", 3)];
+        let lengths = list.iter().pluck(pluck!(.0)).pluck(pluck!(.len())).sum::<usize>();
+        assert_eq!(lengths, 6);
+    }
+
+    #[test]
+    fn range_index() {
+        let list = [vec![0, 1, 2, 3], vec![4, 5, 6, 7]];
+        let middle = list.iter().map(pluck!(&[1..3])).collect::<Vec<&[i32]>>();
+        assert_eq!(middle, &[&[1, 2], &[5, 6]]);
+    }
+
+    #[test]
+    fn range_to_index() {
+        let list = [vec![0, 1, 2, 3], vec![4, 5, 6, 7]];
+        let prefix = list.iter().map(pluck!(&[..2])).collect::<Vec<&[i32]>>();
+        assert_eq!(prefix, &[&[0, 1], &[4, 5]]);
+    }
+
+    #[test]
+    fn range_from_index() {
+        let list = [vec![0, 1, 2, 3], vec![4, 5, 6, 7]];
+        let suffix = list.iter().map(pluck!(&[2..])).collect::<Vec<&[i32]>>();
+        assert_eq!(suffix, &[&[2, 3], &[6, 7]]);
+    }
+
+    #[test]
+    fn range_index_mut() {
+        let mut list = [vec![0, 1, 2], vec![3, 4, 5]];
+        for slice in list.iter_mut().map(pluck!(&mut [1..])) {
+            slice.reverse();
+        }
+        assert_eq!(list, [vec![0, 2, 1], vec![3, 5, 4]]);
+    }
+
+    #[test]
+    fn local_variable_index() {
+        let list = [[0, 1, 2], [3, 4, 5]];
+        let i = 2;
+        let last = list.iter().map(pluck!([i])).collect::<Vec<_>>();
+        assert_eq!(last, &[2, 5]);
+
+        let last_ref = list.iter().map(pluck!(&[i])).collect::<Vec<_>>();
+        assert_eq!(last_ref, &[&2, &5]);
+    }
+
+    #[test]
+    fn expression_index() {
+        let list = [[0, 1, 2], [3, 4, 5]];
+        let i = 0;
+        let second = list.iter().map(pluck!([i + 1])).collect::<Vec<_>>();
+        assert_eq!(second, &[1, 4]);
+
+        let len = list[0].len();
+        let last = list.iter().map(pluck!([len - 1])).collect::<Vec<_>>();
+        assert_eq!(last, &[2, 5]);
+    }
+
+    #[test]
+    fn expression_index_mut() {
+        let mut list = [[0, 1, 2], [3, 4, 5]];
+        let i = 1;
+        for n in list.iter_mut().map(pluck!(&mut [i * 2])) {
+            *n *= 10;
+        }
+        assert_eq!(list, [[0, 1, 20], [3, 4, 50]]);
+        assert_eq!(i, 1);
+    }
+
+    #[test]
+    fn filter_by_property() {
+        struct User { id: u32, active: bool }
+        let list = [User { id: 0, active: true }, User { id: 1, active: false }, User { id: 2, active: true }];
+
+        let active = list.iter().filter(pluck!(.active)).map(pluck!(.id)).collect::<Vec<_>>();
+        assert_eq!(active, &[0, 2]);
+
+        let inactive = list.iter().filter(pluck!(!.active)).map(pluck!(.id)).collect::<Vec<_>>();
+        assert_eq!(inactive, &[1]);
+    }
+
+    #[test]
+    fn negate_method() {
+        let list = [vec![], vec![1], vec![]];
+        let non_empty = list.iter().filter(pluck!(!.is_empty())).count();
+        assert_eq!(non_empty, 1);
+    }
+
+    #[test]
+    fn multiply_properties() {
+        struct Rect { width: u32, height: u32 }
+        let list = [Rect { width: 2, height: 3 }, Rect { width: 4, height: 5 }];
+        let areas = list.iter().map(pluck!(.width * .height)).collect::<Vec<_>>();
+        assert_eq!(areas, &[6, 20]);
+    }
+
+    #[test]
+    fn subtract_properties() {
+        let list = [(5, 3), (10, 1)];
+        let differences = list.iter().map(pluck!(.0 - .1)).collect::<Vec<_>>();
+        assert_eq!(differences, &[2, 9]);
+    }
+
+    #[test]
+    fn add_and_divide_properties() {
+        let list = [[1.0, 3.0], [4.0, 2.0]];
+        let sums = list.iter().map(pluck!([0] + [1])).collect::<Vec<_>>();
+        assert_eq!(sums, &[4.0, 6.0]);
+
+        let ratios = list.iter().map(pluck!([0] / [1])).collect::<Vec<_>>();
+        assert_eq!(ratios, &[1.0 / 3.0, 2.0]);
+    }
+
+    #[test]
+    fn multiply_derefs() {
+        let list = vec![(&2, &3), (&4, &5)];
+        let products = list.into_iter().map(pluck!(*.0 * *.1)).collect::<Vec<_>>();
+        assert_eq!(products, &[6, 20]);
+    }
+
+    #[test]
+    fn binary_operator_with_casts() {
+        let list = [(1u8, 2u16)];
+        let products = list.iter().map(pluck!(.0 as u32 * .1 into u32)).collect::<Vec<_>>();
+        assert_eq!(products, &[2u32]);
+    }
+
+    #[test]
+    fn binary_operator_in_tuple() {
+        let list = [(5, 3)];
+        let both = list.iter().map(pluck!(.0 + .1, .0 - .1)).collect::<Vec<_>>();
+        assert_eq!(both, &[(8, 2)]);
+    }
+
+    #[test]
+    fn to_string_str() {
+        struct Person { name: &'static str }
+        let list = [Person { name: "Alice" }, Person { name: "Bob" }];
+        let names: Vec<String> = list.iter().map(pluck!(.name.to_string())).collect();
+        assert_eq!(names, &["Alice", "Bob"]);
+    }
+
+    #[test]
+    fn to_string_numeric() {
+        let list = [(1, 2.5), (3, 4.0)];
+        let ints: Vec<String> = list.iter().map(pluck!(.0.to_string())).collect();
+        assert_eq!(ints, &["1", "3"]);
+
+        let floats: Vec<String> = list.iter().map(pluck!(.1.to_string())).collect();
+        assert_eq!(floats, &["2.5", "4"]);
+    }
+
+    #[test]
+    fn get_missing_key() {
+        use std::collections::HashMap;
+
+        let list = vec![HashMap::from([("a", 1), ("b", 2)]), HashMap::from([("b", 3)]), HashMap::new()];
+        let a = list.iter().map(pluck!(get["a"])).collect::<Vec<Option<&i32>>>();
+        assert_eq!(a, &[Some(&1), None, None]);
+
+        let b = list.iter().map(pluck!(.get("b"))).collect::<Vec<Option<&i32>>>();
+        assert_eq!(b, &[Some(&2), Some(&3), None]);
+    }
+
+    #[test]
+    fn get_slice_index() {
+        let list = [vec![0, 1], vec![2]];
+        let i = 1;
+        let second = list.iter().map(pluck!(get[i])).collect::<Vec<_>>();
+        assert_eq!(second, &[Some(&1), None]);
+    }
+
+    #[test]
+    fn get_then_optional_chain() {
+        use std::collections::HashMap;
+
+        let list = vec![HashMap::from([("a", (1, "one"))]), HashMap::new()];
+        let names = list.iter().map(pluck!(get["a"]?.1)).collect::<Vec<_>>();
+        assert_eq!(names, &[Some("one"), None]);
+    }
+
+    #[test]
+    fn raw_identifier() {
+        struct Token { r#type: u8, r#match: &'static str }
+        let list = [Token { r#type: 1, r#match: "a" }, Token { r#type: 2, r#match: "b" }];
+
+        let types = list.iter().map(pluck!(.r#type)).collect::<Vec<_>>();
+        assert_eq!(types, &[1, 2]);
+
+        let type_refs = list.iter().map(pluck!(&.r#type)).collect::<Vec<_>>();
+        assert_eq!(type_refs, &[&1, &2]);
+
+        let matches = list.iter().map(pluck!(.r#match, .r#type as u32)).collect::<Vec<_>>();
+        assert_eq!(matches, &[("a", 1), ("b", 2)]);
+    }
+
+    #[test]
+    fn last_element() {
+        let rows = vec![vec![1, 2, 3], vec![], vec![4]];
+        let lasts = rows.iter().map(pluck!(.last())).collect::<Vec<Option<&i32>>>();
+        assert_eq!(lasts, &[Some(&3), None, Some(&4)]);
+
+        let copied = rows.iter().map(pluck!(.last().copied())).collect::<Vec<Option<i32>>>();
+        assert_eq!(copied, &[Some(3), None, Some(4)]);
+    }
+
+    #[test]
+    fn try_pluck_ok() {
+        struct Record { parsed: Result<i32, String> }
+        let list = [Record { parsed: Ok(1) }, Record { parsed: Ok(2) }];
+        let values = list.iter().map(try_pluck!(.parsed?)).collect::<Result<Vec<i32>, String>>();
+        assert_eq!(values, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn try_pluck_err() {
+        struct Record { parsed: Result<i32, String> }
+        let list = [Record { parsed: Ok(1) }, Record { parsed: Err("bad".to_string()) }];
+        let values = list.iter().map(try_pluck!(.parsed?)).collect::<Vec<Result<i32, &String>>>();
+        assert_eq!(values, &[Ok(1), Err(&"bad".to_string())]);
+    }
+
+    #[test]
+    fn try_pluck_chain() {
+        struct Outer { inner: Result<Inner, &'static str> }
+        struct Inner { value: Result<(i32, i32), &'static str> }
+        let list = [
+            Outer { inner: Ok(Inner { value: Ok((1, 2)) }) },
+            Outer { inner: Ok(Inner { value: Err("value") }) },
+            Outer { inner: Err("inner") },
+        ];
+        let values = list.iter().map(try_pluck!(.inner?.value?.1)).collect::<Vec<Result<i32, &&str>>>();
+        assert_eq!(values, &[Ok(2), Err(&"value"), Err(&"inner")]);
+    }
+
+    #[test]
+    fn try_pluck_without_question_mark() {
+        let list = [(1, "a")];
+        let values = list.iter().map(try_pluck!(.0, .1)).collect::<Result<Vec<_>, ()>>();
+        assert_eq!(values, Ok(vec![(1, "a")]));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Person { name: String, age: u32 }
+
+    fn people() -> Vec<Person> {
+        vec![
+            Person { name: "Bob".to_string(), age: 30 },
+            Person { name: "Alice".to_string(), age: 41 },
+            Person { name: "Carol".to_string(), age: 25 },
+        ]
+    }
+
+    #[test]
+    fn sort_by_copy_key() {
+        let mut list = people();
+        list.sort_by_key(sort_key!(.age));
+        assert_eq!(list.iter().map(pluck!(.age)).collect::<Vec<_>>(), &[25, 30, 41]);
+    }
+
+    #[test]
+    fn sort_by_clone_key() {
+        let mut list = people();
+        list.sort_by_key(sort_key!(.name));
+        assert_eq!(list.iter().map(pluck!(.name.as_str())).collect::<Vec<_>>(), &["Alice", "Bob", "Carol"]);
+    }
+
+    #[test]
+    fn sort_by_multiple_keys() {
+        let mut list = people();
+        list.push(Person { name: "Aaron".to_string(), age: 30 });
+        list.sort_by_key(sort_key!(.age, .name));
+        assert_eq!(list.iter().map(pluck!(.name.as_str())).collect::<Vec<_>>(), &["Carol", "Aaron", "Bob", "Alice"]);
+    }
+
+    #[test]
+    fn cmp_by_string() {
+        let mut list = people();
+        list.sort_by(cmp_by!(.name));
+        assert_eq!(list.iter().map(pluck!(.name.as_str())).collect::<Vec<_>>(), &["Alice", "Bob", "Carol"]);
+    }
+
+    #[test]
+    fn cmp_by_reference() {
+        let mut list = people();
+        list.sort_by(cmp_by!(&.name));
+        assert_eq!(list.iter().map(pluck!(.name.as_str())).collect::<Vec<_>>(), &["Alice", "Bob", "Carol"]);
+    }
+
+    #[test]
+    fn cmp_by_reverse() {
+        let mut list = people();
+        list.sort_by(cmp_by!(rev .age));
+        assert_eq!(list.iter().map(pluck!(.age)).collect::<Vec<_>>(), &[41, 30, 25]);
+
+        list.sort_by(cmp_by!(rev .name));
+        assert_eq!(list.iter().map(pluck!(.name.as_str())).collect::<Vec<_>>(), &["Carol", "Bob", "Alice"]);
+    }
+
+    #[test]
+    fn cmp_by_multiple_references() {
+        let mut list = people();
+        list.push(Person { name: "Aaron".to_string(), age: 30 });
+        list.sort_by(cmp_by!(.age, &.name));
+        assert_eq!(list.iter().map(pluck!(.name.as_str())).collect::<Vec<_>>(), &["Carol", "Aaron", "Bob", "Alice"]);
+    }
+
+    #[test]
+    fn deref_count() {
+        let list = vec![&&&0, &&&1, &&&2];
+        let counted = list.iter().map(pluck!(*4)).collect::<Vec<_>>();
+        let starred = list.iter().map(pluck!(****)).collect::<Vec<_>>();
+        assert_eq!(counted, starred);
+
+        let counted = list.into_iter().map(pluck!(*3)).collect::<Vec<_>>();
+        assert_eq!(counted, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn deref_count_one() {
+        let list = vec![&0, &1];
+        let derefed = list.into_iter().map(pluck!(*1)).collect::<Vec<_>>();
+        assert_eq!(derefed, &[0, 1]);
+    }
+
+    #[test]
+    fn deref_count_then_property() {
+        struct Person { age: u32 }
+        let alice = Person { age: 30 };
+        let one = &alice;
+        let two = &one;
+        let list = vec![&two];
+        let ages = list.into_iter().map(pluck!((*3).age)).collect::<Vec<_>>();
+        assert_eq!(ages, &[30]);
+    }
+
+    #[test]
+    fn deref_count_of_property() {
+        struct Wrapper { inner: &'static &'static i32 }
+        let list = [Wrapper { inner: &&1 }, Wrapper { inner: &&2 }];
+        let inners = list.iter().map(pluck!(*2.inner)).collect::<Vec<_>>();
+        assert_eq!(inners, &[1, 2]);
+    }
+
+    #[test]
+    fn reference_to_reference_property() {
+        let list = vec![(&0, "a"), (&1, "b")];
+        let first = list.iter().map(pluck!(&.0)).collect::<Vec<&&i32>>();
+        assert_eq!(first, &[&&0, &&1]);
+    }
+
+    #[test]
+    fn double_reference() {
+        let mut list = vec![(2, "a"), (1, "b")];
+        list.sort_by(cmp_by!(&&.0));
+        assert_eq!(list, &[(1, "b"), (2, "a")]);
+    }
+
+    #[test]
+    fn spaced_double_reference() {
+        let mut list = vec![(2, "a"), (1, "b")];
+        list.sort_by(cmp_by!(& &.1));
+        assert_eq!(list, &[(2, "a"), (1, "b")]);
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Shape {
+        Circle(u32),
+        Rect(u32, u32),
+        Named(String),
+    }
+
+    fn shapes() -> Vec<Shape> {
+        vec![
+            Shape::Circle(1),
+            Shape::Rect(2, 3),
+            Shape::Named("square".to_string()),
+            Shape::Circle(4),
+        ]
+    }
+
+    #[test]
+    fn enum_variant() {
+        let list = shapes();
+        let radii = list.iter().filter_map(pluck!(Shape::Circle(.0))).collect::<Vec<_>>();
+        assert_eq!(radii, &[&1, &4]);
+    }
+
+    #[test]
+    fn enum_variant_later_field() {
+        let list = shapes();
+        let heights = list.iter().filter_map(pluck!(Shape::Rect(.1))).collect::<Vec<_>>();
+        assert_eq!(heights, &[&3]);
+    }
+
+    #[test]
+    fn enum_variant_owned() {
+        let names = shapes().into_iter().filter_map(pluck!(Shape::Named(.0))).collect::<Vec<_>>();
+        assert_eq!(names, &["square".to_string()]);
+    }
+
+    #[test]
+    fn enum_variant_nested_access() {
+        let list = shapes();
+        let lens = list.iter().filter_map(pluck!(Shape::Named(.0.len()))).collect::<Vec<_>>();
+        assert_eq!(lens, &[6]);
+    }
+
+    #[test]
+    fn move_index_across_thread() {
+        fn spawn_third<F>(third: F) -> Vec<i32>
+        where
+            F: Fn(&Vec<i32>) -> &i32 + Send + 'static,
+        {
+            let handle = std::thread::spawn(move || {
+                let list = vec![vec![1, 2, 3], vec![4, 5, 6]];
+                list.iter().map(third).copied().collect()
+            });
+            handle.join().unwrap()
+        }
+
+        let index = 2;
+        assert_eq!(spawn_third(pluck!(move &[index])), &[3, 6]);
+    }
+
+    #[test]
+    fn move_returned_from_fn() {
+        fn lookup(key: String) -> impl Fn(&HashMap<String, i32>) -> Option<&i32> {
+            pluck!(move get[&key])
+        }
+
+        use std::collections::HashMap;
+        let map = HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+        assert_eq!(lookup("b".to_string())(&map), Some(&2));
+    }
+
+    #[test]
+    fn field_then_index() {
+        struct Table { data: Vec<Person> }
+        let list = vec![Table { data: people() }];
+
+        let names = list.iter().map(pluck!(&.data[2].name)).collect::<Vec<_>>();
+        assert_eq!(names, &["Carol"]);
+
+        let third = list.iter().map(pluck!(&.data[2])).collect::<Vec<_>>();
+        assert_eq!(third, &[&Person { name: "Carol".to_string(), age: 25 }]);
+
+        let ages = list.iter().map(pluck!(.data[2].age)).collect::<Vec<_>>();
+        assert_eq!(ages, &[25]);
+    }
+
+    #[test]
+    fn field_then_index_copy() {
+        struct Row { data: Vec<i32> }
+        let list = vec![Row { data: vec![1, 2, 3] }, Row { data: vec![4, 5, 6] }];
+
+        let third = list.iter().map(pluck!(.data[2])).collect::<Vec<_>>();
+        assert_eq!(third, &[3, 6]);
+    }
+
+    #[test]
+    fn get_mut_increments_present() {
+        use std::collections::HashMap;
+        let mut list = vec![
+            HashMap::from([("a", 1), ("b", 2)]),
+            HashMap::from([("b", 3)]),
+            HashMap::from([("a", 4)]),
+        ];
+
+        let key = "a";
+        for value in list.iter_mut().filter_map(pluck!(get_mut[&key])) {
+            *value += 10;
+        }
+
+        let a = list.iter().map(pluck!(get["a"])).collect::<Vec<_>>();
+        assert_eq!(a, &[Some(&11), None, Some(&14)]);
+        assert_eq!(list[1]["b"], 3);
+    }
+
+    #[test]
+    fn ascription_infers_literals() {
+        let list = vec![(1, "a"), (2, "b")];
+        let firsts = list.iter().map(pluck!(.0: u64)).collect::<Vec<_>>();
+        assert_eq!(firsts, &[1, 2]);
+        assert_eq!(std::mem::size_of_val(&firsts[0]), 8);
+    }
+
+    #[test]
+    fn ascription_with_reference() {
+        let list = vec![(1u32, "a"), (2u32, "b")];
+        let firsts = list.iter().map(pluck!(&.0: &u32)).collect::<Vec<_>>();
+        assert_eq!(firsts, &[&1, &2]);
+    }
+
+    #[test]
+    fn ascription_of_tuple() {
+        let list = vec![(1, 2, "a")];
+        let pairs = list.iter().map(pluck!(.0, .1: (u16, u64))).collect::<Vec<_>>();
+        assert_eq!(pairs, &[(1u16, 2u64)]);
+    }
+
+    #[test]
+    fn ascription_of_variant() {
+        let list = shapes();
+        let radii = list.iter().filter_map(pluck!(Shape::Circle(.0): Option<&u32>)).collect::<Vec<_>>();
+        assert_eq!(radii, &[&1, &4]);
+    }
+
+    #[test]
+    fn flatten_option_field() {
+        struct Record { id: u32, maybe: Option<String> }
+        let list = vec![
+            Record { id: 0, maybe: Some("a".to_string()) },
+            Record { id: 1, maybe: None },
+            Record { id: 2, maybe: Some("c".to_string()) },
+        ];
+
+        let present = list.iter().filter_map(pluck!(flatten .maybe)).collect::<Vec<&String>>();
+        assert_eq!(present, &["a", "c"]);
+
+        let options = list.iter().map(pluck!(flatten .maybe)).collect::<Vec<_>>();
+        assert_eq!(options, &[Some(&"a".to_string()), None, Some(&"c".to_string())]);
+        assert_eq!(list.iter().map(pluck!(.id)).sum::<u32>(), 3);
+    }
+
+    #[test]
+    fn flatten_nested_option() {
+        let list = vec![(1, Some((2, "a"))), (3, None)];
+        let inner = list.iter().filter_map(pluck!(flatten .1)).collect::<Vec<_>>();
+        assert_eq!(inner, &[&(2, "a")]);
+    }
+
+    #[test]
+    fn mut_reference_into_indexed_field() {
+        struct Stats { counts: Vec<u32> }
+        let mut list = vec![Stats { counts: vec![0, 5] }, Stats { counts: vec![1, 6] }];
+
+        for count in list.iter_mut().map(pluck!(&mut .counts[0])) {
+            *count += 10;
+        }
+
+        let firsts = list.iter().map(pluck!(.counts[0])).collect::<Vec<_>>();
+        assert_eq!(firsts, &[10, 11]);
+    }
+
+    #[test]
+    fn mut_reference_into_nested_field() {
+        struct Inner { b: Vec<String> }
+        struct Outer { a: Inner }
+        let mut list = vec![Outer { a: Inner { b: vec!["x".to_string()] } }];
+
+        list.iter_mut().map(pluck!(&mut .a.b[0])).for_each(|name| name.push('y'));
+        list.iter_mut().map(pluck!(&mut .a.b)).for_each(|names| names.push("z".to_string()));
+
+        assert_eq!(list[0].a.b, &["xy", "z"]);
+    }
+
+    #[test]
+    fn mut_references_held_together() {
+        let mut list = vec![(vec![1], 0), (vec![2], 0)];
+        let mut firsts = list.iter_mut().map(pluck!(&mut .0[0])).collect::<Vec<_>>();
+
+        for first in firsts.iter_mut() {
+            **first *= 3;
+        }
+        drop(firsts);
+
+        assert_eq!(list, &[(vec![3], 0), (vec![6], 0)]);
+    }
+
+    #[test]
+    fn min_and_max_by_key() {
+        let youngest = people().into_iter().min_by_key(sort_key!(.age)).unwrap();
+        assert_eq!(youngest.name, "Carol");
+
+        let list = people();
+        let oldest = list.iter().max_by_key(sort_key!(.age)).unwrap();
+        assert_eq!(oldest.name, "Alice");
+
+        let last = list.iter().max_by_key(sort_key!(.name)).unwrap();
+        assert_eq!(last.age, 25);
+    }
+
+    #[test]
+    fn min_by_key_empty() {
+        let list: Vec<Person> = Vec::new();
+        assert_eq!(list.iter().min_by_key(sort_key!(.age)), None);
+        assert_eq!(list.into_iter().max_by_key(sort_key!(.name)), None);
+    }
+
+    #[test]
+    fn destructure_newtype() {
+        struct Wrapper(i32);
+        let list = vec![Wrapper(1), Wrapper(2)];
+
+        let refs = list.iter().map(pluck!(let Wrapper(.0))).collect::<Vec<_>>();
+        assert_eq!(refs, &[&1, &2]);
+
+        let owned = list.into_iter().map(pluck!(let Wrapper(.0))).collect::<Vec<_>>();
+        assert_eq!(owned, &[1, 2]);
+    }
+
+    #[test]
+    fn destructure_two_fields() {
+        struct Pair(String, u32);
+        let list = vec![Pair("a".to_string(), 1), Pair("bc".to_string(), 2)];
+
+        let counts = list.iter().map(pluck!(let Pair(.1))).collect::<Vec<_>>();
+        assert_eq!(counts, &[&1, &2]);
+
+        let lens = list.iter().map(pluck!(let Pair(.0.len()))).collect::<Vec<_>>();
+        assert_eq!(lens, &[1, 2]);
+    }
+
+    #[test]
+    fn destructure_by_path() {
+        mod shapes {
+            pub struct Point(pub i32, pub i32);
+        }
+        let list = vec![shapes::Point(1, 2)];
+        let xs = list.iter().map(pluck!(let shapes::Point(.0))).collect::<Vec<_>>();
+        assert_eq!(xs, &[&1]);
+        let ys = list.iter().map(pluck!(let shapes::Point(.1))).collect::<Vec<_>>();
+        assert_eq!(ys, &[&2]);
+    }
+
+    #[test]
+    fn const_generic_index() {
+        fn last<const N: usize>(arr: &[[i32; N]]) -> Vec<i32> {
+            arr.iter().map(pluck!([N - 1])).collect()
+        }
+
+        assert_eq!(last(&[[1], [2]]), &[1, 2]);
+        assert_eq!(last(&[[1, 2, 3], [4, 5, 6]]), &[3, 6]);
+        assert_eq!(last(&[[0; 8]; 3]), &[0, 0, 0]);
+        assert_eq!(last::<4>(&[]), Vec::<i32>::new());
+    }
+
+    struct Ptr<T>(T);
+
+    impl<T> core::ops::Deref for Ptr<T> {
+        type Target = T;
+        fn deref(&self) -> &T {
+            &self.0
+        }
+    }
+
+    impl<T> core::ops::DerefMut for Ptr<T> {
+        fn deref_mut(&mut self) -> &mut T {
+            &mut self.0
+        }
+    }
+
+    #[test]
+    fn explicit_deref() {
+        let list = vec![Box::new(1), Box::new(2)];
+        let derefed = list.iter().map(pluck!(deref)).collect::<Vec<&i32>>();
+        assert_eq!(derefed, &[&1, &2]);
+
+        let list = vec![Ptr(Ptr(1))];
+        let once = list.iter().map(pluck!(deref)).collect::<Vec<&Ptr<i32>>>();
+        assert_eq!(once[0].0, 1);
+    }
+
+    #[test]
+    fn explicit_deref_mut() {
+        let mut list = vec![Ptr(vec![1]), Ptr(vec![2])];
+        list.iter_mut().map(pluck!(deref_mut)).for_each(|inner| inner.push(0));
+        assert_eq!(list.iter().map(pluck!(&.0)).collect::<Vec<_>>(), &[&[1, 0], &[2, 0]]);
+    }
+
+    #[test]
+    fn explicit_deref_of_property() {
+        struct Holder { ptr: Ptr<String>, boxed: Box<u32> }
+        let mut list = vec![Holder { ptr: Ptr("a".to_string()), boxed: Box::new(1) }];
+
+        let names = list.iter().map(pluck!(deref .ptr)).collect::<Vec<&String>>();
+        assert_eq!(names, &["a"]);
+
+        list.iter_mut().map(pluck!(deref_mut .boxed)).for_each(|value| *value += 1);
+        assert_eq!(*list[0].boxed, 2);
+    }
+
+    #[test]
+    fn collect_into_vec() {
+        let list = people();
+        let names = pluck_collect!(&list, .name.as_str() => Vec<&str>);
+        assert_eq!(names, &["Bob", "Alice", "Carol"]);
+    }
+
+    #[test]
+    fn collect_into_hash_set() {
+        use std::collections::HashSet;
+        let list = vec![(1u32, "a"), (2, "b"), (1, "c")];
+        let numbers = pluck_collect!(list.iter(), .0 => HashSet<u32>);
+        assert_eq!(numbers, HashSet::from([1, 2]));
+    }
+
+    #[test]
+    fn collect_owned() {
+        let names = pluck_collect!(people(), .name => Vec<_>);
+        assert_eq!(names, &["Bob", "Alice", "Carol"]);
+    }
+
+    #[test]
+    fn collect_multiple_properties() {
+        use std::collections::BTreeMap;
+        let list = people();
+        let ages = pluck_collect!(&list, .name.as_str(), .age => BTreeMap<_, _>);
+        assert_eq!(ages["Alice"], 41);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Item { category: &'static str, name: &'static str, price: u32 }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item { category: "fruit", name: "apple", price: 2 },
+            Item { category: "bread", name: "rye", price: 4 },
+            Item { category: "fruit", name: "pear", price: 3 },
+        ]
+    }
+
+    #[test]
+    fn group_by_category() {
+        let groups = pluck_group_by!(items(), .category);
+        assert_eq!(groups.len(), 2);
+
+        let fruit = groups["fruit"].iter().map(pluck!(.name)).collect::<Vec<_>>();
+        assert_eq!(fruit, &["apple", "pear"]);
+        assert_eq!(groups["bread"], &[Item { category: "bread", name: "rye", price: 4 }]);
+    }
+
+    #[test]
+    fn group_by_string_key() {
+        let groups = pluck_group_by!(people(), .name.len());
+        let mut lens = groups.keys().copied().collect::<Vec<_>>();
+        lens.sort();
+        assert_eq!(lens, &[3, 5]);
+        assert_eq!(groups[&5].len(), 2);
+    }
+
+    #[test]
+    fn group_by_references() {
+        let list = items();
+        let groups = pluck_group_by!(&list, .name.len(), .category);
+        assert_eq!(groups[&(5, "fruit")], &[&list[0]]);
+        assert_eq!(groups[&(4, "fruit")], &[&list[2]]);
+    }
+
+    #[test]
+    fn group_by_empty() {
+        let groups = pluck_group_by!(Vec::<Item>::new(), .category);
+        assert!(groups.is_empty());
+    }
+
+    #[test]
+    fn cell_copy_out() {
+        use std::cell::Cell;
+        let list = vec![Cell::new(1), Cell::new(2)];
+        list[0].set(5);
+
+        let values = list.iter().map(pluck!(.get())).collect::<Vec<_>>();
+        assert_eq!(values, &[5, 2]);
+    }
+
+    #[test]
+    fn refcell_guards() {
+        use std::cell::RefCell;
+        let list = vec![RefCell::new(1), RefCell::new(2)];
+
+        for mut guard in list.iter().map(pluck!(borrow_mut)) {
+            *guard *= 10;
+        }
+
+        let values = list.iter().map(pluck!(borrow)).map(|guard| *guard).collect::<Vec<_>>();
+        assert_eq!(values, &[10, 20]);
+    }
+
+    #[test]
+    fn refcell_property() {
+        use std::cell::RefCell;
+        struct Node { children: RefCell<Vec<u32>> }
+        let list = vec![Node { children: RefCell::new(vec![1]) }];
+
+        list.iter().map(pluck!(borrow_mut .children)).for_each(|mut guard| guard.push(2));
+        let guard = list.iter().map(pluck!(borrow .children)).next().unwrap();
+        assert_eq!(*guard, &[1, 2]);
+    }
+
+    #[test]
+    fn retain_by_bool_property() {
+        struct Task { name: &'static str, keep: bool }
+        let mut list = vec![
+            Task { name: "a", keep: true },
+            Task { name: "b", keep: false },
+            Task { name: "c", keep: true },
+        ];
+
+        list.retain(pluck!(.keep));
+        assert_eq!(list.iter().map(pluck!(.name)).collect::<Vec<_>>(), &["a", "c"]);
+
+        list.retain(pluck!(!.keep));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn filter_by_bool_property() {
+        let list = vec![(1, true), (2, false), (3, true)];
+
+        let kept = list.iter().filter(pluck!(.1)).map(pluck!(.0)).collect::<Vec<_>>();
+        assert_eq!(kept, &[1, 3]);
+
+        let dropped = list.iter().filter(pluck!(!.1)).map(pluck!(.0)).collect::<Vec<_>>();
+        assert_eq!(dropped, &[2]);
+    }
+
+    #[test]
+    fn filter_by_bool_method() {
+        let mut list = vec![vec![], vec![1], vec![]];
+        list.retain(pluck!(!.is_empty()));
+        assert_eq!(list, &[vec![1]]);
+    }
+
+    trait Area {
+        fn area(&self) -> u32;
+        fn label(&self) -> &str;
+    }
+
+    struct Square(u32);
+    struct Rectangle(u32, u32);
+
+    impl Area for Square {
+        fn area(&self) -> u32 {
+            self.0 * self.0
+        }
+        fn label(&self) -> &str {
+            "square"
+        }
+    }
+
+    impl Area for Rectangle {
+        fn area(&self) -> u32 {
+            self.0 * self.1
+        }
+        fn label(&self) -> &str {
+            "rectangle"
+        }
+    }
+
+    #[test]
+    fn boxed_trait_methods() {
+        let list: Vec<Box<dyn Area>> = vec![Box::new(Square(2)), Box::new(Rectangle(2, 3))];
+
+        let areas = list.iter().map(pluck!(.area())).collect::<Vec<_>>();
+        assert_eq!(areas, &[4, 6]);
+
+        let labels = list.iter().map(pluck!(.label())).collect::<Vec<_>>();
+        assert_eq!(labels, &["square", "rectangle"]);
+    }
+
+    #[test]
+    fn boxed_trait_methods_in_property() {
+        struct Entry { shape: Box<dyn Area> }
+        let list = vec![Entry { shape: Box::new(Square(3)) }];
+        let areas = list.iter().map(pluck!(.shape.area())).collect::<Vec<_>>();
+        assert_eq!(areas, &[9]);
+    }
+
+    #[test]
+    fn method_returning_reference() {
+        let list = vec!["a".to_string(), "bc".to_string()];
+        let strs = list.iter().map(pluck!(.as_str())).collect::<Vec<&str>>();
+        assert_eq!(strs, &["a", "bc"]);
+        drop(strs);
+
+        let owned = list.into_iter().map(pluck!(.into_bytes())).collect::<Vec<Vec<u8>>>();
+        assert_eq!(owned, &[b"a".to_vec(), b"bc".to_vec()]);
+    }
+
+    #[test]
+    fn method_reference_composes_with_suffix() {
+        let list = vec![vec![1, 2], vec![3]];
+        let firsts = list.iter().map(pluck!(.as_slice()[0])).collect::<Vec<i32>>();
+        assert_eq!(firsts, &[1, 3]);
+
+        let refs = list.iter().map(pluck!(&.as_slice()[0])).collect::<Vec<&i32>>();
+        assert_eq!(refs, &[&1, &3]);
+    }
+
+    #[test]
+    fn method_result_returned_as_is_with_mut() {
+        let mut list = vec!["a".to_string()];
+        list.iter_mut().map(pluck!(.as_mut_str())).for_each(|s| s.make_ascii_uppercase());
+        assert_eq!(list, &["A"]);
+    }
+
+    #[test]
+    fn scan_running_sum() {
+        struct Record { delta: u32 }
+        let list = vec![Record { delta: 1 }, Record { delta: 2 }, Record { delta: 3 }];
+
+        let totals = list.iter().scan(0, pluck_scan!(+= .delta)).collect::<Vec<_>>();
+        assert_eq!(totals, &[1, 3, 6]);
+    }
+
+    #[test]
+    fn scan_operators() {
+        let list = vec![(2, "a"), (3, "b")];
+
+        assert_eq!(list.iter().scan(20, pluck_scan!(-= .0)).collect::<Vec<_>>(), &[18, 15]);
+        assert_eq!(list.iter().scan(1, pluck_scan!(*= .0)).collect::<Vec<_>>(), &[2, 6]);
+        assert_eq!(list.iter().scan(60, pluck_scan!(/= .0)).collect::<Vec<_>>(), &[30, 10]);
+    }
+
+    #[test]
+    fn scan_clone_state() {
+        let list = vec![(1, "a"), (2, "bc")];
+        let joined = list.iter().scan(String::new(), pluck_scan!(+= .1)).collect::<Vec<_>>();
+        assert_eq!(joined, &["a", "abc"]);
+    }
+
+    #[test]
+    fn str_arguments() {
+        let list = vec!["hello", "world"];
+
+        let heads = list.iter().map(pluck!(&[..2])).collect::<Vec<&str>>();
+        assert_eq!(heads, &["he", "wo"]);
+
+        let lens = list.into_iter().map(pluck!(.len())).collect::<Vec<_>>();
+        assert_eq!(lens, &[5, 5]);
+    }
+
+    #[test]
+    fn unsized_arguments() {
+        let words = ["ab", "cd"];
+        let strs: Vec<&str> = words.to_vec();
+        let tails = strs.into_iter().map(pluck!(&[1..])).collect::<Vec<&str>>();
+        assert_eq!(tails, &["b", "d"]);
+
+        let first = [1, 2];
+        let second = [3];
+        let slices: Vec<&[i32]> = vec![&first, &second];
+        let heads = slices.iter().map(pluck!([0])).collect::<Vec<_>>();
+        assert_eq!(heads, &[1, 3]);
+
+        let rests = slices.into_iter().map(pluck!(&[1..])).collect::<Vec<&[i32]>>();
+        assert_eq!(rests, &[&[2][..], &[]]);
+    }
+
+    #[test]
+    fn unsized_function_argument() {
+        pluck_fn!(fn first_char(&str) -> Option<char> = .chars().next());
+        pluck_fn!(fn head(&[u8]) -> &u8 = &[0]);
+
+        assert_eq!(first_char("abc"), Some('a'));
+        assert_eq!(head(b"xyz"), &b'x');
+    }
+
+    #[test]
+    fn map_over_option_argument() {
+        let list = vec![Some(people().remove(0)), None];
+
+        let names = list.iter().map(pluck!(?.name.as_str())).collect::<Vec<Option<&str>>>();
+        assert_eq!(names, &[Some("Bob"), None]);
+
+        let refs = list.iter().map(pluck!(?&.name)).collect::<Vec<Option<&String>>>();
+        assert_eq!(refs, &[Some(&"Bob".to_string()), None]);
+
+        let ages = list.iter().map(pluck!(?.age, .name.len())).collect::<Vec<_>>();
+        assert_eq!(ages, &[Some((30, 3)), None]);
+    }
+
+    #[test]
+    fn map_over_result_argument() {
+        let list: Vec<Result<(u32, &str), &str>> = vec![Ok((1, "a")), Err("bad")];
+        let firsts = list.iter().map(pluck!(?.0)).collect::<Vec<_>>();
+        assert_eq!(firsts, &[Ok(1), Err(&"bad")]);
+    }
+
+    #[test]
+    fn map_over_option_then_chain() {
+        let list = vec![Some((Some(1), "a")), Some((None, "b")), None];
+        let inner = list.iter().map(pluck!(?.0?)).collect::<Vec<_>>();
+        assert_eq!(inner, &[Some(Some(1)), Some(None), None]);
+    }
+
+    #[test]
+    fn flat_map_children() {
+        struct Node { children: Vec<i32> }
+        let outer = vec![
+            Node { children: vec![1, 2] },
+            Node { children: vec![] },
+            Node { children: vec![3] },
+        ];
+
+        let all = outer.iter().flat_map(pluck!(.children.iter())).collect::<Vec<&i32>>();
+        assert_eq!(all, &[&1, &2, &3]);
+
+        let copied = outer.iter().flat_map(pluck!(.children.iter().copied())).sum::<i32>();
+        assert_eq!(copied, 6);
+
+        let owned = outer.into_iter().flat_map(pluck!(.children)).collect::<Vec<i32>>();
+        assert_eq!(owned, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn clone_method_collects_owned() {
+        let list = people();
+        let names = list.iter().map(pluck!(.name.clone())).collect::<Vec<String>>();
+        assert_eq!(names, &["Bob", "Alice", "Carol"]);
+
+        let same = list.iter().map(pluck!(clone .name)).collect::<Vec<String>>();
+        assert_eq!(names, same);
+    }
+
+    #[test]
+    fn into_iter_moves_out() {
+        let list = vec![("a".to_string(), vec![1]), ("b".to_string(), vec![2])];
+        let (names, numbers): (Vec<String>, Vec<Vec<i32>>) =
+            list.into_iter().map(pluck!(.0, .1)).unzip();
+        assert_eq!(names, &["a", "b"]);
+        assert_eq!(numbers, &[vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn into_iter_moves_one_field() {
+        let names = people().into_iter().map(pluck!(.name)).collect::<Vec<String>>();
+        assert_eq!(names, &["Bob", "Alice", "Carol"]);
+    }
+
+    #[test]
+    fn iter_borrows_or_copies() {
+        let list = vec![("a".to_string(), 1), ("b".to_string(), 2)];
+
+        let copies = list.iter().map(pluck!(.1)).collect::<Vec<i32>>();
+        let refs = list.iter().map(pluck!(&.0)).collect::<Vec<&String>>();
+        let clones = list.iter().map(pluck!(clone .0)).collect::<Vec<String>>();
+        let explicit = list.iter().map(pluck!(copy .1)).collect::<Vec<i32>>();
+
+        assert_eq!(copies, explicit);
+        assert_eq!(refs, clones.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn chained_map_indices() {
+        use std::collections::HashMap;
+        let list = vec![
+            HashMap::from([("a", HashMap::from([("b", 1)]))]),
+            HashMap::from([("a", HashMap::from([("b", 2), ("c", 3)]))]),
+        ];
+
+        let bs = list.iter().map(pluck!(["a"]["b"])).collect::<Vec<_>>();
+        assert_eq!(bs, &[1, 2]);
+
+        let refs = list.iter().map(pluck!(&["a"]["b"])).collect::<Vec<_>>();
+        assert_eq!(refs, &[&1, &2]);
+    }
+
+    #[test]
+    fn mixed_vec_and_map_indices() {
+        use std::collections::HashMap;
+        let list = vec![
+            vec![HashMap::from([("key", "x")])],
+            vec![HashMap::from([("key", "y")]), HashMap::new()],
+        ];
+
+        let keys = list.iter().map(pluck!([0]["key"])).collect::<Vec<_>>();
+        assert_eq!(keys, &["x", "y"]);
+
+        let lens = list.iter().map(pluck!([0]["key"].len())).collect::<Vec<_>>();
+        assert_eq!(lens, &[1, 1]);
+
+        let found = list.iter().map(pluck!([0].get("key")?.len())).collect::<Vec<_>>();
+        assert_eq!(found, &[Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn unwrap_method_result() {
+        let list = vec![vec![1, 2], vec![3, 4]];
+        let i = 1;
+        let second = list.iter().map(pluck!(.get(i) unwrap)).collect::<Vec<_>>();
+        assert_eq!(second, &[&2, &4]);
+
+        let results: Vec<Result<u8, String>> = vec![Ok(1), Ok(2)];
+        let parsed = results.into_iter().map(pluck!(unwrap)).collect::<Vec<_>>();
+        assert_eq!(parsed, &[1, 2]);
+    }
+
+    #[test]
+    fn unwrap_option_field() {
+        struct Record { id: Option<u32>, name: Option<String> }
+        let list = vec![Record { id: Some(1), name: Some("a".to_string()) }];
+
+        let ids = list.iter().map(pluck!(.id unwrap)).collect::<Vec<_>>();
+        assert_eq!(ids, &[1]);
+
+        let names = list.into_iter().map(pluck!(.name unwrap .len())).collect::<Vec<_>>();
+        assert_eq!(names, &[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_none_panics() {
+        let list = vec![vec![1]];
+        let _ = list.iter().map(pluck!(.get(1) unwrap)).collect::<Vec<_>>();
+    }
+
+    struct Transaction { amount: f64, count: u32 }
+
+    fn transactions() -> Vec<Transaction> {
+        vec![
+            Transaction { amount: 1.25, count: 2 },
+            Transaction { amount: 2.5, count: 3 },
+            Transaction { amount: 0.25, count: 4 },
+        ]
+    }
+
+    #[test]
+    fn sum_amounts() {
+        let list = transactions();
+        assert_eq!(pluck_sum!(&list, .amount), 4.0);
+        assert_eq!(pluck_sum!(list.iter(), .count), 9);
+        assert_eq!(pluck_sum!(list, .count + .count), 18);
+    }
+
+    #[test]
+    fn product_counts() {
+        let list = transactions();
+        assert_eq!(pluck_product!(&list, .count), 24);
+        assert_eq!(pluck_product!(&list, .amount), 0.78125);
+    }
+
+    #[test]
+    fn aggregate_empty() {
+        let list: Vec<Transaction> = Vec::new();
+        assert_eq!(pluck_sum!(&list, .amount), 0.0);
+        assert_eq!(pluck_product!(&list, .count), 1);
+    }
+
+    #[test]
+    fn dedup_by_id() {
+        #[derive(Debug, PartialEq)]
+        struct Record { id: String, value: u32 }
+        let record = |id: &str, value| Record { id: id.to_string(), value };
+        let mut list = vec![record("a", 1), record("a", 2), record("b", 3), record("a", 4)];
+
+        list.dedup_by_key(dedup_key!(.id));
+        assert_eq!(list.iter().map(pluck!(.value)).collect::<Vec<_>>(), &[1, 3, 4]);
+    }
+
+    #[test]
+    fn dedup_by_multiple_keys() {
+        let mut list = vec![(1, 'a', 0), (1, 'a', 1), (1, 'b', 2), (2, 'b', 3)];
+        list.dedup_by_key(dedup_key!(.0, .1));
+        assert_eq!(list, &[(1, 'a', 0), (1, 'b', 2), (2, 'b', 3)]);
+    }
+
+    #[test]
+    fn partition_by_bool_property() {
+        #[derive(Debug, PartialEq)]
+        struct User { name: &'static str, is_active: bool }
+        let list = vec![
+            User { name: "a", is_active: true },
+            User { name: "b", is_active: false },
+            User { name: "c", is_active: true },
+        ];
+
+        let (active, inactive) = list.iter().partition::<Vec<_>, _>(pluck!(copy .is_active));
+        assert_eq!(active.into_iter().map(pluck!(.name)).collect::<Vec<_>>(), &["a", "c"]);
+        assert_eq!(inactive.into_iter().map(pluck!(.name)).collect::<Vec<_>>(), &["b"]);
+
+        let (active, inactive): (Vec<_>, Vec<_>) = list.into_iter().partition(pluck!(.is_active));
+        assert_eq!(active.len(), 2);
+        assert_eq!(inactive, &[User { name: "b", is_active: false }]);
+    }
+
+    fn shout(name: &str) -> String {
+        name.to_uppercase()
+    }
+
+    fn add(a: u32, b: u32) -> u32 {
+        a + b
+    }
+
+    #[test]
+    fn free_function() {
+        let list = people();
+        let loud = list.iter().map(pluck!(shout(&.name))).collect::<Vec<_>>();
+        assert_eq!(loud, &["BOB", "ALICE", "CAROL"]);
+    }
+
+    #[test]
+    fn free_function_multiple_arguments() {
+        let list = vec![(1, 2), (3, 4)];
+        let sums = list.iter().map(pluck!(add(.0, .1))).collect::<Vec<_>>();
+        assert_eq!(sums, &[3, 7]);
+
+        let nested = list.iter().map(pluck!(add(add(.0, .1), .1 * .1))).collect::<Vec<_>>();
+        assert_eq!(nested, &[7, 23]);
+    }
+
+    #[test]
+    fn free_function_composes() {
+        let list = people();
+        let pairs = list.iter().map(pluck!(shout(&.name), .age)).collect::<Vec<_>>();
+        assert_eq!(pairs[0], ("BOB".to_string(), 30));
+
+        let plus = list.iter().map(pluck!(.age + add(.age, .age))).collect::<Vec<_>>();
+        assert_eq!(plus, &[90, 123, 75]);
+
+        let cast = list.iter().map(pluck!(add(.age, .age) as u8)).collect::<Vec<_>>();
+        assert_eq!(cast, &[60, 82, 50]);
+    }
+
+    #[test]
+    fn free_function_with_other_forms() {
+        fn birthday(age: &mut u32) {
+            *age += 1;
+        }
+
+        let loud = vec!["ab".to_string()].iter().map(pluck!(shout(.as_str()))).collect::<Vec<_>>();
+        assert_eq!(loud, &["AB"]);
+
+        let mut list = people();
+        list.iter_mut().for_each(pluck!(birthday(&mut .age)));
+        assert_eq!(list.iter().map(pluck!(.age)).collect::<Vec<_>>(), &[31, 42, 26]);
+
+        let sums = list.iter().map(pluck!(add(copy .age, .age))).collect::<Vec<_>>();
+        assert_eq!(sums, &[62, 84, 52]);
+
+        let initials = list.iter().map(pluck!(shout(&.name[..1]))).collect::<Vec<_>>();
+        assert_eq!(initials, &["B", "A", "C"]);
+    }
+
+    #[test]
+    fn keywords_shadow_free_functions() {
+        fn copy(age: &u32) -> u32 {
+            age + 100
+        }
+        fn clone(name: &str) -> String {
+            name.to_uppercase()
+        }
+        assert_eq!((copy(&1), clone("a")), (101, "A".to_string()));
+
+        let list = people();
+        let ages = list.iter().map(pluck!(copy(.age))).collect::<Vec<u32>>();
+        assert_eq!(ages, &[30, 41, 25]);
+        let names = list.iter().map(pluck!(clone(.name))).collect::<Vec<String>>();
+        assert_eq!(names, &["Bob", "Alice", "Carol"]);
+    }
+
+    #[test]
+    fn reference_field_without_prefix() {
+        struct Person { name: &'static str }
+        let list = vec![Person { name: "a" }, Person { name: "b" }];
+
+        let names: Vec<&str> = list.iter().map(pluck!(.name)).collect();
+        assert_eq!(names, &["a", "b"]);
+
+        let doubled: Vec<&&str> = list.iter().map(pluck!(&.name)).collect();
+        assert_eq!(doubled, &[&"a", &"b"]);
+    }
+
+    #[test]
+    fn deref_before_borrowing() {
+        let list = people();
+
+        let strs: Vec<&str> = list.iter().map(pluck!(&*.name)).collect();
+        assert_eq!(strs, &["Bob", "Alice", "Carol"]);
+
+        let strings: Vec<&String> = list.iter().map(pluck!(&.name)).collect();
+        assert_eq!(strings, &["Bob", "Alice", "Carol"]);
+    }
+
+    #[test]
+    fn lookup_table_by_key() {
+        let table = map_by_key!(items(), .name);
+        assert_eq!(table.len(), 3);
+        assert_eq!(table["rye"].price, 4);
+        assert_eq!(table["pear"], Item { category: "fruit", name: "pear", price: 3 });
+    }
+
+    #[test]
+    fn lookup_table_keeps_last() {
+        let list = items();
+        let table = map_by_key!(&list, .category);
+        assert_eq!(table.len(), 2);
+        assert_eq!(table["fruit"].name, "pear");
+        assert_eq!(table["bread"], &list[1]);
+    }
+
+    #[test]
+    fn lookup_table_owned_key() {
+        let table = map_by_key!(people(), .name);
+        assert_eq!(table["Alice"].age, 41);
+        assert!(!table.contains_key("Dave"));
+    }
+
+    #[test]
+    fn index_from_end() {
+        let list = vec![vec![1, 2, 3], vec![4, 5]];
+
+        assert_eq!(list.iter().map(pluck!([^1])).collect::<Vec<_>>(), &[3, 5]);
+        assert_eq!(list.iter().map(pluck!([^2])).collect::<Vec<_>>(), &[2, 4]);
+
+        let n = 2;
+        assert_eq!(list.iter().map(pluck!([^n - 1])).collect::<Vec<_>>(), &[3, 5]);
+    }
+
+    #[test]
+    fn index_from_end_in_chain() {
+        struct Row { cells: Vec<(u32, &'static str)> }
+        let mut list = vec![Row { cells: vec![(1, "a"), (2, "b")] }];
+
+        assert_eq!(list.iter().map(pluck!(.cells[^1].1)).collect::<Vec<_>>(), &["b"]);
+
+        list.iter_mut().map(pluck!(&mut .cells[^1].0)).for_each(|cell| *cell = 9);
+        assert_eq!(list[0].cells, &[(1, "a"), (9, "b")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^3 is out of range for length 2")]
+    fn index_from_end_out_of_range() {
+        let list = vec![vec![1, 2]];
+        let _ = list.iter().map(pluck!([^3])).collect::<Vec<_>>();
+    }
+
+    #[test]
+    #[should_panic(expected = "index ^0 is out of range for length 2")]
+    fn index_from_end_zero() {
+        let list = vec![vec![1, 2]];
+        let _ = list.iter().map(pluck!([^0])).collect::<Vec<_>>();
+    }
+
+    #[test]
+    fn or_else_only_on_misses() {
+        use std::cell::Cell;
+        use std::collections::HashMap;
+
+        let list = vec![HashMap::from([("a", 1)]), HashMap::from([("b", 2)]), HashMap::new()];
+        let calls = Cell::new(0);
+        let compute = || {
+            calls.set(calls.get() + 1);
+            0
+        };
+
+        let key = "a";
+        let found = list.iter().map(pluck_or_else!(.get(&key).copied(), compute)).collect::<Vec<_>>();
+        assert_eq!(found, &[1, 0, 0]);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn or_else_inline_closure() {
+        let list = vec![(Some("a".to_string()), 1), (None, 2)];
+        let names = list
+            .into_iter()
+            .map(pluck_or_else!(.0, || "x".repeat(2)))
+            .collect::<Vec<_>>();
+        assert_eq!(names, &["a", "xx"]);
+    }
+
+    #[test]
+    fn turbofish_method() {
+        struct Line { text: &'static str }
+        let list = vec![Line { text: "1" }, Line { text: "x" }];
+
+        let parsed = list.iter().map(pluck!(.text.parse::<i32>())).collect::<Vec<_>>();
+        assert_eq!(parsed[0], Ok(1));
+        assert!(parsed[1].is_err());
+
+        let oks = list[..1].iter().map(pluck!(.text.parse::<u8>() unwrap)).collect::<Vec<_>>();
+        assert_eq!(oks, &[1]);
+    }
+
+    #[test]
+    fn turbofish_nested_generics() {
+        let list = vec![vec![1, 2], vec![3]];
+        let sets = list
+            .iter()
+            .map(pluck!(.iter().copied().collect::<std::collections::BTreeSet<u8>>().len()))
+            .collect::<Vec<_>>();
+        assert_eq!(sets, &[2, 1]);
+
+        let nested = list.iter().map(pluck!(.iter().collect::<Vec<&u8>>())).collect::<Vec<_>>();
+        assert_eq!(nested, &[vec![&1, &2], vec![&3]]);
+    }
+
+    #[test]
+    fn combine_sum() {
+        let list = transactions();
+        assert_eq!(list.iter().fold(0.0, combine!(+ .amount)), 4.0);
+        assert_eq!(list.iter().fold(0, combine!(+ .count)), 9);
+    }
+
+    #[test]
+    fn combine_product() {
+        let list = transactions();
+        assert_eq!(list.iter().fold(1, combine!(* .count)), 24);
+    }
+
+    #[test]
+    fn combine_min_max() {
+        let list = transactions();
+        assert_eq!(list.iter().fold(u32::MAX, combine!(min .count)), 2);
+        assert_eq!(list.iter().fold(0, combine!(max .count)), 4);
+        assert_eq!(list.iter().fold(0.0, combine!(max .amount)), 2.5);
+    }
+
+    #[test]
+    fn combine_empty() {
+        let list: Vec<Transaction> = Vec::new();
+        assert_eq!(list.iter().fold(7, combine!(+ .count)), 7);
+    }
+
+    #[test]
+    fn stacked_flatten() {
+        struct Record { x: Option<Option<i32>> }
+        let list = vec![Record { x: Some(Some(1)) }, Record { x: Some(None) }, Record { x: None }];
+
+        let refs = list.iter().map(pluck!(flatten flatten .x)).collect::<Vec<Option<&i32>>>();
+        assert_eq!(refs, &[Some(&1), None, None]);
+
+        let present = list.iter().filter_map(pluck!(flatten flatten .x)).collect::<Vec<_>>();
+        assert_eq!(present, &[&1]);
+
+        let owned = list.into_iter().map(pluck!(.x.flatten())).collect::<Vec<Option<i32>>>();
+        assert_eq!(owned, &[Some(1), None, None]);
+    }
+
+    #[test]
+    fn triple_flatten() {
+        let list = vec![(Some(Some(Some("a"))),), (Some(Some(None)),), (None,)];
+        let inner = list.iter().map(pluck!(flatten flatten flatten .0)).collect::<Vec<_>>();
+        assert_eq!(inner, &[Some(&"a"), None, None]);
+    }
+
+    #[test]
+    fn string_byte_range() {
+        let list = vec!["apple".to_string(), "banana".to_string()];
+
+        let prefixes = list.iter().map(pluck!(&[0..3])).collect::<Vec<&str>>();
+        assert_eq!(prefixes, &["app", "ban"]);
+
+        let suffixes = list.iter().map(pluck!(&[3..])).collect::<Vec<&str>>();
+        assert_eq!(suffixes, &["le", "ana"]);
+
+        let people = people();
+        let initials = people.iter().map(pluck!(&.name[..1])).collect::<Vec<&str>>();
+        assert_eq!(initials, &["B", "A", "C"]);
+    }
+
+    #[test]
+    fn mutable_string_byte_range() {
+        let mut list = vec!["apple".to_string(), "banana".to_string()];
+
+        list.iter_mut().map(pluck!(&mut [0..1])).for_each(str::make_ascii_uppercase);
+        assert_eq!(list, &["Apple", "Banana"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a char boundary")]
+    fn string_byte_range_not_on_char_boundary() {
+        let list = vec!["héllo".to_string()];
+        let _ = list.iter().map(pluck!(&[0..2])).collect::<Vec<&str>>();
+    }
+
+    #[test]
+    fn lock_copies_out_of_guard() {
+        use std::sync::{Arc, Mutex};
+
+        struct Counter {
+            hits: u32,
+            name: String,
+        }
+
+        let list = vec![
+            Arc::new(Mutex::new(Counter { hits: 1, name: "a".to_string() })),
+            Arc::new(Mutex::new(Counter { hits: 2, name: "b".to_string() })),
+        ];
+
+        let hits = list.iter().map(pluck!(lock .hits)).collect::<Vec<u32>>();
+        assert_eq!(hits, &[1, 2]);
+
+        let names = list.iter().map(pluck!(lock clone .name)).collect::<Vec<String>>();
+        assert_eq!(names, &["a", "b"]);
+
+        let lens = list.iter().map(pluck!(lock .name.len())).sum::<usize>();
+        assert_eq!(lens, 2);
+
+        // The lock is released once the lambda returns.
+        list[0].lock().unwrap().hits += 1;
+        assert_eq!(list.iter().map(pluck!(lock .hits)).sum::<u32>(), 4);
+    }
+
+    #[test]
+    fn lock_whole_value() {
+        use std::sync::Mutex;
+
+        let list = vec![Mutex::new(1), Mutex::new(2)];
+        assert_eq!(list.iter().map(pluck!(lock)).collect::<Vec<i32>>(), &[1, 2]);
+    }
 
     #[test]
-    fn ref_to_triple_deref() {
-        let list = vec![&&&0, &&&1, &&&2];
-        let derefed = list.into_iter().map(pluck!(&***)).collect::<Vec<_>>();
-        assert_eq!(derefed, &[&0, &1, &2]);
+    fn key_value_pair() {
+        use std::collections::HashMap;
+
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        struct Id(u32);
+        struct Person {
+            id: Id,
+            name: String,
+        }
+
+        let list = vec![
+            Person { id: Id(1), name: "Alice".to_string() },
+            Person { id: Id(2), name: "Bob".to_string() },
+        ];
+
+        let names = list.iter().map(pluck!(.id => clone .name)).collect::<HashMap<Id, String>>();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[&Id(1)], "Alice");
+        assert_eq!(names[&Id(2)], "Bob");
+
+        let borrowed = list.iter().map(pluck!(.id => &*.name)).collect::<HashMap<Id, &str>>();
+        assert_eq!(borrowed[&Id(2)], "Bob");
+
+        let by_name = pluck_collect!(&list, .name => .id.0 => HashMap<String, u32>);
+        assert_eq!(by_name["Alice"], 1);
     }
 
     #[test]
-    fn mut_ref_deref() {
-        let mut list = vec![0, 1, 2];
-        let derefed = list.iter_mut().map(pluck!(&mut *)).collect::<Vec<_>>();
-        assert_eq!(derefed, &[&0, &1, &2]);
+    fn key_value_pair_with_tuple_key() {
+        let list = [(1, 'a', "x"), (2, 'b', "y")];
+        let pairs = list.iter().map(pluck!(.0, .1 => .2)).collect::<Vec<_>>();
+        assert_eq!(pairs, &[((1, 'a'), "x"), ((2, 'b'), "y")]);
     }
 
     #[test]
-    fn deref_index() {
-        let mut list = vec![[&0], [&1], [&2]];
-        let derefed = list.iter_mut().map(pluck!(*[0])).collect::<Vec<_>>();
-        assert_eq!(derefed, &[0, 1, 2]);
+    fn deref_newtype_field() {
+        use std::ops::Deref;
+
+        struct Meters(f64);
+        impl Deref for Meters {
+            type Target = f64;
+            fn deref(&self) -> &f64 {
+                &self.0
+            }
+        }
+
+        let list = vec![Meters(1.5), Meters(-2.0)];
+
+        let fields = list.iter().map(pluck!(.0)).collect::<Vec<f64>>();
+        assert_eq!(fields, &[1.5, -2.0]);
+
+        let targets = list.iter().map(pluck!(**)).collect::<Vec<f64>>();
+        assert_eq!(targets, &[1.5, -2.0]);
+
+        let abs = list.iter().map(pluck!(.abs())).collect::<Vec<f64>>();
+        assert_eq!(abs, &[1.5, 2.0]);
     }
 
     #[test]
-    fn nested_index() {
-        let mut list = vec![[[0]], [[1]], [[2]]];
-        let derefed = list.iter_mut().map(pluck!([0][0])).collect::<Vec<_>>();
-        assert_eq!(derefed, &[0, 1, 2]);
+    fn deref_newtype_field_shadows_target() {
+        use std::ops::Deref;
+
+        struct Outer(Inner);
+        struct Inner(&'static str);
+        impl Deref for Outer {
+            type Target = Inner;
+            fn deref(&self) -> &Inner {
+                &self.0
+            }
+        }
+
+        let list = vec![Outer(Inner("a"))];
+        let own = list.iter().map(pluck!(&.0)).collect::<Vec<&Inner>>();
+        assert_eq!(own[0].0, "a");
+
+        let target = list.iter().map(pluck!((**).0)).collect::<Vec<&str>>();
+        assert_eq!(target, &["a"]);
+
+        let nested = list.iter().map(pluck!(.0.0)).collect::<Vec<&str>>();
+        assert_eq!(nested, &["a"]);
     }
 
     #[test]
-    fn nested_property() {
-        struct Person { address: Address }
-        struct Address { street: &'static str }
+    fn as_ref_and_as_mut() {
+        struct Record {
+            maybe: Option<String>,
+        }
 
-        let mut list = [Person { address: Address { street: "foo" } }];
-        let streets = list.iter_mut().map(pluck!(.address.street)).collect::<Vec<_>>();
-        assert_eq!(streets, &["foo"]);
+        let mut list = vec![Record { maybe: Some("a".to_string()) }, Record { maybe: None }];
+
+        let refs = list.iter().map(pluck!(as_ref .maybe)).collect::<Vec<Option<&String>>>();
+        assert_eq!(refs, &[Some(&"a".to_string()), None]);
+
+        list.iter_mut().filter_map(pluck!(as_mut .maybe)).for_each(|s| s.push('!'));
+        let present = list.iter().filter_map(pluck!(as_ref .maybe)).collect::<Vec<_>>();
+        assert_eq!(present, &["a!"]);
+
+        let maybes = list.iter().map(pluck!(&.maybe)).collect::<Vec<_>>();
+        let bare = maybes.into_iter().filter_map(pluck!(as_ref)).collect::<Vec<_>>();
+        assert_eq!(bare, &["a!"]);
     }
 
     #[test]
-    fn index_deref() {
-        let mut list = vec![[&[0]], [&[1]], [&[2]]];
-        let derefed = list.iter_mut().map(pluck!((*[0])[0])).collect::<Vec<_>>();
-        assert_eq!(derefed, &[0, 1, 2]);
+    fn as_ref_composes_with_access() {
+        struct Inner {
+            maybe: Option<u32>,
+        }
+        struct Outer {
+            a: Inner,
+            result: Result<u32, String>,
+        }
+
+        let list = vec![
+            Outer { a: Inner { maybe: Some(1) }, result: Ok(2) },
+            Outer { a: Inner { maybe: None }, result: Err("e".to_string()) },
+        ];
+
+        let nested = list.iter().filter_map(pluck!(as_ref .a.maybe)).collect::<Vec<_>>();
+        assert_eq!(nested, &[&1]);
+
+        let results = list.iter().map(pluck!(as_ref .result)).collect::<Vec<_>>();
+        assert_eq!(results, &[Ok(&2), Err(&"e".to_string())]);
     }
 
     #[test]
-    fn index_deref_property() {
-        struct Person { name: &'static str }
-        let mut list = vec![[&Person { name: "Alice" }]];
-        let derefed = list.iter_mut().map(pluck!((*[0]).name)).collect::<Vec<_>>();
-        assert_eq!(derefed, &["Alice"]);
+    fn inspect_pluck_runs_action() {
+        struct Event {
+            name: String,
+            size: usize,
+        }
+
+        let list = vec![
+            Event { name: "open".to_string(), size: 1 },
+            Event { name: "close".to_string(), size: 2 },
+        ];
+
+        let mut count = 0;
+        let mut bytes = 0;
+        let sizes = list
+            .iter()
+            .inspect(inspect_pluck!(.size => |size| {
+                count += 1;
+                bytes += size;
+            }))
+            .map(pluck!(.size))
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, &[1, 2]);
+        assert_eq!((count, bytes), (2, 3));
+
+        let mut names = Vec::new();
+        list.iter().for_each(inspect_pluck!(&.name => |name: &String| names.push(name.clone())));
+        assert_eq!(names, &["open", "close"]);
+    }
+
+    #[test]
+    fn inspect_pluck_with_function() {
+        fn check(size: usize) {
+            assert!(size > 0);
+        }
+
+        let list = vec![(1, "a"), (2, "b")];
+        list.iter().for_each(inspect_pluck!(.0 => check));
+
+        let mut pairs = Vec::new();
+        list.iter().for_each(inspect_pluck!(.1 => .0 => |pair| pairs.push(pair)));
+        assert_eq!(pairs, &[("a", 1), ("b", 2)]);
+    }
+
+    #[test]
+    fn or_default_inserts_default() {
+        #[derive(Debug, PartialEq)]
+        struct Record {
+            tags: Option<Vec<&'static str>>,
+        }
+
+        let mut list = vec![Record { tags: None }, Record { tags: Some(vec!["a"]) }];
+
+        list.iter_mut().map(pluck!(&mut .tags or_default)).for_each(|tags| tags.push("b"));
+        assert_eq!(list, &[Record { tags: Some(vec!["b"]) }, Record { tags: Some(vec!["a", "b"]) }]);
+
+        let lens = list.iter_mut().map(pluck!(&mut .tags or_default)).map(|tags| tags.len());
+        assert!(lens.eq([1, 2]));
+    }
+
+    #[test]
+    fn or_default_on_nested_property() {
+        let mut list = vec![((None::<u32>,),), ((Some(3),),)];
+        list.iter_mut().map(pluck!(&mut .0.0 or_default)).for_each(|num| *num *= 2);
+        assert_eq!(list, &[((Some(0),),), ((Some(6),),)]);
+    }
+
+    #[test]
+    fn array_of_properties() {
+        struct Particle {
+            x: f64,
+            y: f64,
+            z: f64,
+        }
+
+        let list = vec![Particle { x: 1.0, y: 2.0, z: 3.0 }, Particle { x: 4.0, y: 5.0, z: 6.0 }];
+
+        let points = list.iter().map(pluck!([.x, .y, .z])).collect::<Vec<[f64; 3]>>();
+        assert_eq!(points, &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let planar = list.iter().map(pluck!([.x, .y,])).collect::<Vec<[f64; 2]>>();
+        assert_eq!(planar, &[[1.0, 2.0], [4.0, 5.0]]);
+
+        let single = list.iter().map(pluck!([.z,])).collect::<Vec<[f64; 1]>>();
+        assert_eq!(single, &[[3.0], [6.0]]);
+
+        let sums = list.iter().map(pluck!([.x + .y, .z])).collect::<Vec<_>>();
+        assert_eq!(sums, &[[3.0, 3.0], [9.0, 6.0]]);
+    }
+
+    #[test]
+    fn array_brackets_without_comma_index() {
+        let list = vec![vec![1, 2], vec![3, 4]];
+        assert_eq!(list.iter().map(pluck!([1])).collect::<Vec<_>>(), &[2, 4]);
+        assert_eq!(list.iter().map(pluck!([^2])).collect::<Vec<_>>(), &[1, 3]);
+
+        let i = 0;
+        assert_eq!(list.iter().map(pluck!([i])).collect::<Vec<_>>(), &[1, 3]);
+        assert_eq!(list.iter().map(pluck!([.len(), [0]])).collect::<Vec<_>>(), &[[2, 1], [2, 3]]);
+    }
+
+    #[test]
+    fn disjoint_mutable_properties() {
+        #[derive(Debug, PartialEq)]
+        struct Pair {
+            a: u32,
+            b: String,
+        }
+
+        let mut list = vec![Pair { a: 1, b: "x".to_string() }, Pair { a: 2, b: "y".to_string() }];
+
+        for (a, b) in list.iter_mut().map(pluck!(&mut .a, &mut .b)) {
+            *a *= 10;
+            b.push('!');
+        }
+        assert_eq!(list, &[Pair { a: 10, b: "x!".to_string() }, Pair { a: 20, b: "y!".to_string() }]);
+
+        let mut nested = vec![((1, 2), 3)];
+        let (first, last): (&mut i32, &mut i32) = nested.iter_mut().map(pluck!(&mut .0.1, &mut .1)).next().unwrap();
+        std::mem::swap(first, last);
+        assert_eq!(nested, &[((1, 3), 2)]);
+    }
+
+    #[test]
+    fn unsafe_deref_raw_pointers() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        let mut points = [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+        let consts = points.iter().map(|point| point as *const Point).collect::<Vec<_>>();
+
+        // SAFETY: Every pointer comes from a reference into `points`.
+        let copied = consts.clone().into_iter().map(pluck!(unsafe *)).collect::<Vec<_>>();
+        assert_eq!(copied, points);
+        let xs = consts.iter().map(pluck!(unsafe (**).x)).collect::<Vec<_>>();
+        assert_eq!(xs, &[1, 3]);
+        let ys = consts.iter().map(pluck!(unsafe &(**).y)).collect::<Vec<&i32>>();
+        assert_eq!(ys, &[&2, &4]);
+
+        let muts = points.iter_mut().map(|point| point as *mut Point).collect::<Vec<_>>();
+        // SAFETY: The pointers are unique, since they come from `iter_mut`.
+        muts.into_iter().map(pluck!(unsafe &mut (*).x)).for_each(|x| *x += 10);
+        assert_eq!(points.iter().map(pluck!(.x)).collect::<Vec<_>>(), &[11, 13]);
+    }
+
+    #[test]
+    fn index_through_deref_to_slice() {
+        use std::rc::Rc;
+
+        let boxed: Vec<Box<[i32]>> = vec![Box::new([1, 2]), Box::new([3, 4])];
+        assert_eq!(boxed.iter().map(pluck!([0])).collect::<Vec<_>>(), &[1, 3]);
+        assert_eq!(boxed.iter().map(pluck!(&[1])).collect::<Vec<_>>(), &[&2, &4]);
+        assert_eq!(boxed.iter().map(pluck!([^1])).collect::<Vec<_>>(), &[2, 4]);
+        assert_eq!(boxed.iter().map(pluck!(&[..1])).collect::<Vec<&[i32]>>(), &[&[1], &[3]]);
+
+        let shared: Vec<Rc<[i32]>> = vec![Rc::from([1, 2]), Rc::from([3, 4])];
+        assert_eq!(shared.iter().map(pluck!([1])).collect::<Vec<_>>(), &[2, 4]);
+        assert_eq!(shared.iter().map(pluck!(&[0])).collect::<Vec<_>>(), &[&1, &3]);
+        assert_eq!(shared.iter().map(pluck!(get[2])).collect::<Vec<_>>(), &[None, None]);
+    }
+
+    #[test]
+    fn mutable_index_through_deref_to_slice() {
+        let mut boxed: Vec<Box<[i32]>> = vec![Box::new([1, 2]), Box::new([3, 4])];
+        boxed.iter_mut().map(pluck!(&mut [0])).for_each(|first| *first *= 10);
+        boxed.iter_mut().map(pluck!(&mut [^1])).for_each(|last| *last += 1);
+        assert_eq!(boxed, vec![Box::from([10, 3]), Box::from([30, 5])]);
+    }
+
+    #[test]
+    fn index_slice_behind_field() {
+        struct Row {
+            cells: Vec<i32>,
+            boxed: Box<[i32]>,
+        }
+
+        let mut list = vec![Row { cells: vec![1, 2], boxed: Box::new([3]) }];
+        assert_eq!(list.iter().map(pluck!(.cells[1])).collect::<Vec<_>>(), &[2]);
+        assert_eq!(list.iter().map(pluck!(&.boxed[0])).collect::<Vec<_>>(), &[&3]);
+        assert_eq!(list.iter().map(pluck!(.cells[0] + .boxed[0])).collect::<Vec<_>>(), &[4]);
+
+        list.iter_mut().map(pluck!(&mut .boxed[0])).for_each(|cell| *cell = 0);
+        list.iter_mut().map(pluck!(&mut .cells[^1])).for_each(|cell| *cell = 0);
+        assert_eq!(list.iter().map(pluck!(.cells[1], .boxed[0])).collect::<Vec<_>>(), &[(0, 0)]);
+    }
+
+    #[test]
+    fn ok_and_err() {
+        struct Parsed {
+            input: &'static str,
+            result: Result<i32, std::num::ParseIntError>,
+        }
+
+        let list = ["1", "x", "3"]
+            .iter()
+            .map(|input| Parsed { input, result: input.parse() })
+            .collect::<Vec<_>>();
+
+        let values = list.iter().filter_map(pluck!(ok .result)).copied().collect::<Vec<i32>>();
+        assert_eq!(values, &[1, 3]);
+
+        let errors = list.iter().filter_map(pluck!(err .result)).collect::<Vec<_>>();
+        assert_eq!(errors, &[&"x".parse::<i32>().unwrap_err()]);
+        assert_eq!(list.iter().map(pluck!(.input)).collect::<Vec<_>>(), &["1", "x", "3"]);
+
+        let options = list.iter().map(pluck!(ok .result)).collect::<Vec<Option<&i32>>>();
+        assert_eq!(options, &[Some(&1), None, Some(&3)]);
+
+        let owned = list.into_iter().filter_map(pluck!(.result.ok())).collect::<Vec<i32>>();
+        assert_eq!(owned, &[1, 3]);
+    }
+
+    #[test]
+    fn reorder_tuple() {
+        let list = vec![(1, "a"), (2, "b")];
+        let swapped = list.iter().map(pluck!(.1, .0)).collect::<Vec<(&str, i32)>>();
+        assert_eq!(swapped, &[("a", 1), ("b", 2)]);
+
+        let list = vec![(1, "a", 'x'), (2, "b", 'y')];
+        let rotated = list.iter().map(pluck!(.2, .0, .1)).collect::<Vec<_>>();
+        assert_eq!(rotated, &[('x', 1, "a"), ('y', 2, "b")]);
+
+        let owned = vec![(1, "a".to_string(), vec![1])];
+        let moved = owned.into_iter().map(pluck!(.2, .1, .0)).collect::<Vec<_>>();
+        assert_eq!(moved, &[(vec![1], "a".to_string(), 1)]);
+    }
+
+    #[test]
+    fn group_and_map_into_btree_map() {
+        use std::collections::BTreeMap;
+
+        let people = people();
+
+        let by_name: BTreeMap<String, &Person> = map_by_key!(&people, .name => BTreeMap);
+        assert!(by_name.keys().eq(["Alice", "Bob", "Carol"]));
+        assert_eq!(by_name["Carol"].age, 25);
+
+        let list = vec![
+            Person { name: "Bob".to_string(), age: 30 },
+            Person { name: "Alice".to_string(), age: 41 },
+            Person { name: "Bob".to_string(), age: 25 },
+        ];
+        let groups = pluck_group_by!(list, .name => std::collections::BTreeMap);
+        let sorted = groups
+            .iter()
+            .map(|(name, group)| (name.as_str(), group.iter().map(pluck!(.age)).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        assert_eq!(sorted, &[("Alice", vec![41]), ("Bob", vec![30, 25])]);
+    }
+
+    #[test]
+    fn generic_struct_with_phantom_field() {
+        use std::marker::PhantomData;
+
+        struct Wrapper<T> {
+            value: T,
+            _marker: PhantomData<T>,
+        }
+
+        fn wrap<T>(value: T) -> Wrapper<T> {
+            Wrapper { value, _marker: PhantomData }
+        }
+
+        fn values<T: Clone>(list: &[Wrapper<T>]) -> Vec<T> {
+            list.iter().map(pluck!(clone .value)).collect()
+        }
+
+        fn refs<T>(list: &[Wrapper<T>]) -> Vec<&T> {
+            list.iter().map(pluck!(&.value)).collect()
+        }
+
+        let numbers = vec![wrap(1), wrap(2)];
+        assert_eq!(values(&numbers), &[1, 2]);
+        assert_eq!(refs(&numbers), &[&1, &2]);
+        assert_eq!(numbers.iter().map(pluck!(.value)).collect::<Vec<i32>>(), &[1, 2]);
+
+        let strings = vec![wrap("a".to_string())];
+        assert_eq!(values(&strings), &["a"]);
+        assert_eq!(refs(&strings), &["a"]);
+        assert_eq!(strings.into_iter().map(pluck!(.value)).collect::<Vec<String>>(), &["a"]);
+    }
+
+    #[test]
+    fn manually_drop_derefs() {
+        use std::mem::ManuallyDrop;
+
+        struct Slot {
+            count: ManuallyDrop<u32>,
+            name: ManuallyDrop<String>,
+        }
+
+        let mut list = vec![Slot { count: ManuallyDrop::new(1), name: ManuallyDrop::new("a".to_string()) }];
+
+        assert_eq!(list.iter().map(pluck!(*.count)).collect::<Vec<_>>(), &[1]);
+        assert_eq!(list.iter().map(pluck!(&*.name)).collect::<Vec<&String>>(), &["a"]);
+        assert_eq!(list.iter().map(pluck!(.name.len())).collect::<Vec<_>>(), &[1]);
+
+        list.iter_mut().map(pluck!(&mut *.count)).for_each(|count| *count += 1);
+        assert_eq!(list.iter().map(pluck!(*.count)).collect::<Vec<_>>(), &[2]);
+
+        for slot in &mut list {
+            // SAFETY: Each name is dropped exactly once and not used after.
+            unsafe { ManuallyDrop::drop(&mut slot.name) };
+        }
+    }
+
+    #[test]
+    fn unsafe_assume_init() {
+        use std::mem::MaybeUninit;
+
+        struct Slot {
+            value: MaybeUninit<String>,
+        }
+
+        let list = vec![Slot { value: MaybeUninit::new("a".to_string()) }];
+
+        // SAFETY: Every slot is initialized.
+        let values = list.iter().map(pluck!(unsafe assume_init .value)).collect::<Vec<&String>>();
+        assert_eq!(values, &["a"]);
+
+        let bare = vec![MaybeUninit::new(1), MaybeUninit::new(2)];
+        let ints = bare.iter().map(pluck!(unsafe assume_init)).collect::<Vec<&i32>>();
+        assert_eq!(ints, &[&1, &2]);
+
+        for slot in list {
+            // SAFETY: Every slot is initialized and read here for the last time.
+            drop(unsafe { slot.value.assume_init() });
+        }
+    }
+
+    #[test]
+    fn copied_and_cloned() {
+        struct Record<'a> {
+            maybe_ref: Option<&'a i32>,
+            name: Option<&'a String>,
+        }
+
+        let (one, name) = (1, "a".to_string());
+        let list = vec![
+            Record { maybe_ref: Some(&one), name: Some(&name) },
+            Record { maybe_ref: None, name: None },
+        ];
+
+        let nums = list.iter().map(pluck!(copied .maybe_ref)).collect::<Vec<Option<i32>>>();
+        assert_eq!(nums, &[Some(1), None]);
+
+        let names = list.iter().filter_map(pluck!(cloned .name)).collect::<Vec<String>>();
+        assert_eq!(names, &["a"]);
+
+        let nested = vec![vec![1, 2], vec![]];
+        let firsts = nested.iter().map(pluck!(copied .first())).collect::<Vec<Option<i32>>>();
+        assert_eq!(firsts, &[Some(1), None]);
+
+        let refs = vec![Some(&one), None];
+        let bare = refs.into_iter().map(pluck!(copied)).collect::<Vec<Option<i32>>>();
+        assert_eq!(bare, &[Some(1), None]);
+    }
+
+    #[test]
+    fn named_variant_field() {
+        enum E {
+            Point { x: i32, y: i32 },
+            Label { text: String },
+            Unit,
+        }
+
+        let list = vec![
+            E::Point { x: 1, y: 2 },
+            E::Label { text: "a".to_string() },
+            E::Unit,
+            E::Point { x: 3, y: 4 },
+        ];
+
+        let xs = list.iter().filter_map(pluck!(E::Point { x })).collect::<Vec<&i32>>();
+        assert_eq!(xs, &[&1, &3]);
+
+        let ys = list.iter().filter_map(pluck!(E::Point { y, .. })).collect::<Vec<_>>();
+        assert_eq!(ys, &[&2, &4]);
+
+        let texts = list.iter().map(pluck!(E::Label { text })).collect::<Vec<Option<&String>>>();
+        assert_eq!(texts, &[None, Some(&"a".to_string()), None, None]);
+
+        let owned = list.into_iter().filter_map(pluck!(E::Label { text })).collect::<Vec<String>>();
+        assert_eq!(owned, &["a"]);
+    }
+
+    #[test]
+    fn is_sorted_by_key() {
+        let mut list = people();
+        assert!(!list.is_sorted_by_key(sort_key!(.age)));
+        assert!(!list.is_sorted_by_key(sort_key!(.name)));
+
+        list.sort_by_key(sort_key!(.age));
+        assert!(list.is_sorted_by_key(sort_key!(.age)));
+        assert!(list.is_sorted_by_key(sort_key!(.age, .name)));
+        assert!(!list.is_sorted_by_key(sort_key!(.name)));
+
+        assert!(list.iter().is_sorted_by_key(sort_key!(.age)));
+        assert!(Vec::<Person>::new().is_sorted_by_key(sort_key!(.name)));
+    }
+
+    #[test]
+    fn pluck_if_active_names() {
+        struct User {
+            name: String,
+            active: bool,
+            age: u32,
+        }
+        let list = vec![
+            User { name: "Alice".to_string(), active: true, age: 41 },
+            User { name: "Bob".to_string(), active: false, age: 30 },
+            User { name: "Carol".to_string(), active: true, age: 25 },
+        ];
+
+        let active = list.iter().filter_map(pluck_if!(.active => .name)).collect::<Vec<String>>();
+        assert_eq!(active, &["Alice", "Carol"]);
+
+        let inactive = list.iter().filter_map(pluck_if!(!.active => .name, .age)).collect::<Vec<_>>();
+        assert_eq!(inactive, vec![("Bob".to_string(), 30)]);
+
+        let adults = list.iter().filter_map(pluck_if!(.age.gt(&28) => .name)).collect::<Vec<_>>();
+        assert_eq!(adults, &["Alice", "Bob"]);
+
+        let owned = list.into_iter().filter_map(pluck_if!(.active => .age)).collect::<Vec<u32>>();
+        assert_eq!(owned, &[41, 25]);
+    }
+
+    #[test]
+    fn split_vec_field() {
+        struct Series {
+            data: Vec<i32>,
+        }
+        let list = vec![Series { data: vec![1, 2, 3, 4] }, Series { data: vec![5, 6] }];
+
+        let halves = list.iter().map(split!(.data, 2)).collect::<Vec<_>>();
+        assert_eq!(halves, vec![(&[1, 2][..], &[3, 4][..]), (&[5, 6][..], &[][..])]);
+
+        let n = 0;
+        let first = list.iter().map(split!(.data, n)).next();
+        assert_eq!(first, Some((&[][..], &[1, 2, 3, 4][..])));
+
+        let pairs = vec![(0, [1, 2, 3])];
+        let split = pairs.iter().map(split!(.1, 1)).collect::<Vec<_>>();
+        assert_eq!(split, vec![(&[1][..], &[2, 3][..])]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_out_of_bounds() {
+        let list = vec![vec![1, 2]];
+        let _ = list.iter().map(split!([..], 3)).count();
+    }
+
+    #[test]
+    fn deref_string_and_vec_targets() {
+        let names = vec!["Bob".to_string(), "Alice".to_string()];
+        let strs = names.iter().map(pluck!(&**)).collect::<Vec<&str>>();
+        assert_eq!(strs, &["Bob", "Alice"]);
+        let ascribed = names.iter().map(pluck!(&*: &str)).collect::<Vec<_>>();
+        assert_eq!(ascribed, strs);
+        let chars = names.iter().map(pluck!((**).chars().count())).collect::<Vec<_>>();
+        assert_eq!(chars, &[3, 5]);
+
+        let lists = vec![vec![1, 2], vec![3]];
+        let slices = lists.iter().map(pluck!(&**)).collect::<Vec<&[i32]>>();
+        assert_eq!(slices, vec![&[1, 2][..], &[3][..]]);
+        let ascribed = lists.iter().map(pluck!(&*: &[i32])).collect::<Vec<_>>();
+        assert_eq!(ascribed, slices);
+
+        let people = people();
+        let names = people.iter().map(pluck!(&.name: &str)).collect::<Vec<_>>();
+        assert_eq!(names, &["Bob", "Alice", "Carol"]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vec_of_plucks() {
+        struct Color {
+            r: u8,
+            g: u8,
+            b: u8,
+        }
+        let list = vec![Color { r: 1, g: 2, b: 3 }, Color { r: 4, g: 5, b: 6 }];
+
+        let channels = list.iter().map(pluck!(vec![.r, .g, .b])).collect::<Vec<Vec<u8>>>();
+        assert_eq!(channels, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let reversed = list.iter().map(pluck!(vec![.b, .g, .r,])).collect::<Vec<_>>();
+        assert_eq!(reversed, vec![vec![3, 2, 1], vec![6, 5, 4]]);
+
+        let single = list.into_iter().map(pluck!(vec![.g])).collect::<Vec<_>>();
+        assert_eq!(single, vec![vec![2], vec![5]]);
+
+        let widened = people().iter().map(pluck!(vec![.age, .name.len() as u32])).collect::<Vec<_>>();
+        assert_eq!(widened, vec![vec![30, 3], vec![41, 5], vec![25, 5]]);
+    }
+
+    #[test]
+    fn ref_normalizes_to_single_reference() {
+        struct Row {
+            name: &'static str,
+            count: i32,
+            label: String,
+            nested: (&'static i32, i32),
+        }
+        let list = vec![
+            Row { name: "a", count: 1, label: "x".to_string(), nested: (&10, 20) },
+            Row { name: "b", count: 2, label: "y".to_string(), nested: (&30, 40) },
+        ];
+
+        let names = list.iter().map(pluck!(ref .name)).collect::<Vec<&str>>();
+        assert_eq!(names, &["a", "b"]);
+        let counts = list.iter().map(pluck!(ref .count)).collect::<Vec<&i32>>();
+        assert_eq!(counts, &[&1, &2]);
+        let labels = list.iter().map(pluck!(ref .label)).collect::<Vec<&String>>();
+        assert_eq!(labels, &["x", "y"]);
+
+        let pairs = list.iter().map(pluck!(ref .nested.0, ref .nested.1)).collect::<Vec<(&i32, &i32)>>();
+        assert_eq!(pairs, vec![(&10, &20), (&30, &40)]);
+    }
+
+    #[test]
+    fn map_while_stops_at_first_none() {
+        struct Record {
+            maybe: Option<String>,
+            count: Option<u32>,
+        }
+        let list = vec![
+            Record { maybe: Some("a".to_string()), count: Some(1) },
+            Record { maybe: Some("b".to_string()), count: None },
+            Record { maybe: None, count: Some(3) },
+            Record { maybe: Some("d".to_string()), count: Some(4) },
+        ];
+
+        let owned = list.iter().map_while(pluck!(clone .maybe)).collect::<Vec<String>>();
+        assert_eq!(owned, &["a", "b"]);
+        let method = list.iter().map_while(pluck!(.maybe.clone())).collect::<Vec<String>>();
+        assert_eq!(method, owned);
+        let skipped = list.iter().filter_map(pluck!(clone .maybe)).collect::<Vec<String>>();
+        assert_eq!(skipped, &["a", "b", "d"]);
+
+        let borrowed = list.iter().map_while(pluck!(flatten .maybe)).collect::<Vec<&String>>();
+        assert_eq!(borrowed, &["a", "b"]);
+        let counts = list.iter().map_while(pluck!(.count)).collect::<Vec<u32>>();
+        assert_eq!(counts, &[1]);
+
+        let moved = list.into_iter().map_while(pluck!(.maybe)).collect::<Vec<String>>();
+        assert_eq!(moved, &["a", "b"]);
+    }
+
+    #[test]
+    fn pluck_or_default_with_generic_commas() {
+        use std::collections::HashMap;
+
+        let empty = HashMap::<u8, u8>::new();
+        let list = vec![HashMap::from([(1u8, HashMap::from([(2u8, 3u8)]))]), HashMap::new()];
+
+        let inner = list.iter().map(pluck_or!(.get(&1), &empty)).collect::<Vec<_>>();
+        assert_eq!(inner, vec![&HashMap::from([(2, 3)]), &empty]);
+
+        let owned = list
+            .iter()
+            .map(pluck_or!(.get(&1).cloned(), HashMap::<u8, u8>::new()))
+            .collect::<Vec<_>>();
+        assert_eq!(owned, vec![HashMap::from([(2, 3)]), HashMap::new()]);
+
+        let lazy = list
+            .iter()
+            .map(pluck_or_else!(.get(&1).cloned(), HashMap::<u8, u8>::default))
+            .collect::<Vec<_>>();
+        assert_eq!(lazy, owned);
+    }
+
+    #[test]
+    fn inspect_pluck_borrows_captures() {
+        use std::collections::HashMap;
+
+        let key = "a".to_string();
+        let list = vec![HashMap::from([("a".to_string(), 1)]), HashMap::new()];
+
+        let mut found = Vec::new();
+        list.iter().for_each(inspect_pluck!(.get(&key) => |value| found.push(value)));
+        assert_eq!(found, &[Some(&1), None]);
+        assert_eq!(key, "a");
+
+        let mut seen = 0;
+        let mut count = |value: Option<&i32>| seen += value.map_or(0, |_| 1);
+        list.iter().for_each(inspect_pluck!(.get(&key) => count));
+        list.iter().for_each(inspect_pluck!(.get(key.as_str()) => count));
+        assert_eq!((seen, key.len()), (2, 1));
+    }
+
+    #[test]
+    fn casts_with_operator_types() {
+        let list = vec![(1u32, 2u32), (3, 4)];
+        let addrs = list.iter().map(pluck!(&.0 as *const u32 as usize)).collect::<Vec<_>>();
+        assert_eq!(addrs, vec![&list[0].0 as *const u32 as usize, &list[1].0 as *const u32 as usize]);
+
+        let ptrs = list.iter().map(pluck!(&.1 as *const u32)).collect::<Vec<_>>();
+        assert_eq!(unsafe { *ptrs[1] }, 4);
+
+        let sums = list.iter().map(pluck!(.0 as u64 + .1 as u64)).collect::<Vec<_>>();
+        assert_eq!(sums, &[3, 7]);
+
+        let errors = people()
+            .into_iter()
+            .map(pluck!(.name into Box<dyn std::error::Error + Send + Sync>))
+            .collect::<Vec<_>>();
+        assert_eq!(errors[0].to_string(), "Bob");
+    }
+
+    #[test]
+    fn question_mark_in_method_closure_is_not_optional_chaining() {
+        struct Row {
+            v: Vec<Option<u32>>,
+            parent: Option<Box<Row>>,
+        }
+        let list = vec![
+            Row { v: vec![Some(1), None], parent: None },
+            Row { v: vec![Some(2)], parent: Some(Box::new(Row { v: vec![None], parent: None })) },
+        ];
+
+        let counts = list.iter().map(pluck!(.v.iter().filter_map(|x| Some((*x)? + 1)).count())).collect::<Vec<usize>>();
+        assert_eq!(counts, &[1, 1]);
+
+        let firsts = list.iter().map(pluck!(.v.first().map(|x| x.map(|y| y * 2)))).collect::<Vec<_>>();
+        assert_eq!(firsts, &[Some(Some(2)), Some(Some(4))]);
+
+        let parents = list.iter().map(pluck!(.parent?.v.len())).collect::<Vec<Option<usize>>>();
+        assert_eq!(parents, &[None, Some(1)]);
+
+        let grouped = list.iter().map(pluck!((.v[0]?).pow(2))).collect::<Vec<_>>();
+        assert_eq!(grouped, &[Some(1), Some(4)]);
+
+        let summed = list.iter().map(pluck!(add(.v[0]?, .v[0]?))).collect::<Vec<_>>();
+        assert_eq!(summed, &[Some(2), Some(4)]);
+    }
+
+    #[test]
+    fn ascription_applies_inside_optional_chain() {
+        struct Row {
+            o: Option<u64>,
+            name: Option<String>,
+        }
+        let list = vec![Row { o: Some(1), name: Some("a".to_string()) }, Row { o: None, name: None }];
+
+        let numbers = list.iter().map(pluck!(.o?: u64)).collect::<Vec<Option<u64>>>();
+        assert_eq!(numbers, &[Some(1), None]);
+
+        let names = list.iter().map(pluck!(&.name?: &str)).collect::<Vec<Option<&str>>>();
+        assert_eq!(names, &[Some("a"), None]);
+
+        let lens = list.iter().map(pluck!(.name?.len(): usize)).collect::<Vec<_>>();
+        assert_eq!(lens, &[Some(1), None]);
+
+        let outer = vec![Some(Row { o: Some(2), name: None }), None];
+        let inner = outer.iter().map(pluck!(?.o: Option<Option<u64>>)).collect::<Vec<_>>();
+        assert_eq!(inner, &[Some(Some(2)), None]);
+    }
+
+    #[test]
+    fn bare_tuple_struct_is_called_not_matched() {
+        #[derive(Debug, PartialEq)]
+        struct Wrapper(u32);
+        let list = vec![Wrapper(1), Wrapper(2)];
+
+        let fields = list.iter().map(pluck!(let Wrapper(.0))).collect::<Vec<&u32>>();
+        assert_eq!(fields, &[&1, &2]);
+
+        let rewrapped = list.iter().map(pluck!(Wrapper(.0))).collect::<Vec<Wrapper>>();
+        assert_eq!(rewrapped, list);
+
+        let nested = list.iter().map(pluck!(Wrapper(.0 * .0))).collect::<Vec<_>>();
+        assert_eq!(nested, &[Wrapper(1), Wrapper(4)]);
     }
 }
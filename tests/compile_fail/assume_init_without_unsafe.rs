@@ -0,0 +1,7 @@
+use pluck::*;
+use std::mem::MaybeUninit;
+
+fn main() {
+    let list = [(MaybeUninit::new(1),)];
+    let _ = list.iter().map(pluck!(assume_init .0)).collect::<Vec<_>>();
+}
@@ -0,0 +1,9 @@
+use pluck::*;
+
+struct NotClone;
+struct Wrapper { inner: NotClone }
+
+fn main() {
+    let list = [Wrapper { inner: NotClone }];
+    let _ = list.iter().map(pluck!(clone .inner)).collect::<Vec<_>>();
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(0, "a")];
+    let _: Vec<i32> = pluck_collect!(&list, .0);
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(0, "a")];
+    let _ = list.iter().fold(0, combine!(- .0));
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(String::new(), 0)];
+    let _ = list.iter().map(pluck!(copy .0)).collect::<Vec<_>>();
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(0, "a")];
+    let _ = list.iter().map(pluck!(.)).collect::<Vec<_>>();
+}
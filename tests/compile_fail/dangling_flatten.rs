@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(Some(0), "a")];
+    let _ = list.iter().map(pluck!(flatten)).collect::<Vec<_>>();
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(0u8, "a")];
+    let _ = list.iter().map(pluck!(.0 into)).collect::<Vec<_>>();
+}
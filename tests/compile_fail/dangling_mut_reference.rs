@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let mut list = [(0, "a")];
+    let _ = list.iter_mut().map(pluck!(&mut)).collect::<Vec<_>>();
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(0, "a")];
+    list.iter().for_each(inspect_pluck!(.0));
+}
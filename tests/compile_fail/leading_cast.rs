@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [0u8];
+    let _ = list.iter().map(pluck!(as u32)).collect::<Vec<_>>();
+}
@@ -0,0 +1,7 @@
+use pluck::*;
+use std::collections::BTreeMap;
+
+fn main() {
+    let list = vec![(0, "a")];
+    let _ = map_by_key!(list, .0 => BTreeMap<i32, (i32, &str)>);
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(0, "a".to_string())];
+    let _ = list.iter().map(pluck!(.1)).collect::<Vec<_>>();
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = vec!["apple".to_string()];
+    let _ = list.iter().map(pluck!(&mut [0..3])).collect::<Vec<&mut str>>();
+}
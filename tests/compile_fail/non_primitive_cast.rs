@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(String::new(), 0)];
+    let _ = list.iter().map(pluck!(.0 as u8)).collect::<Vec<_>>();
+}
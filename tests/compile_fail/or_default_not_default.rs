@@ -0,0 +1,8 @@
+use pluck::*;
+
+struct NoDefault;
+
+fn main() {
+    let mut list = [(None::<NoDefault>,)];
+    list.iter_mut().map(pluck!(&mut .0 or_default)).for_each(drop);
+}
@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [vec![0]];
+    let _ = list.iter().map(pluck_or!(.get(0))).collect::<Vec<_>>();
+}
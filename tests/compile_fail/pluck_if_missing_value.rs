@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(true, "a")];
+    let _ = list.iter().filter_map(pluck_if!(.0 =>)).count();
+}
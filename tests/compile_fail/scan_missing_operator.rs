@@ -0,0 +1,6 @@
+use pluck::*;
+
+fn main() {
+    let list = [(0, "a")];
+    let _ = list.iter().scan(0, pluck_scan!(.0)).collect::<Vec<i32>>();
+}
@@ -0,0 +1,9 @@
+use pluck::*;
+
+enum E {
+    A(i32),
+}
+
+fn main() {
+    let _ = pluck!(E::A(.6));
+}
@@ -0,0 +1,106 @@
+//! Flattening nested collections with lambdas that return borrowing
+//! iterators.
+
+use pluck::*;
+
+struct Group {
+    name: &'static str,
+    items: Vec<u32>,
+}
+
+fn groups() -> Vec<Group> {
+    vec![
+        Group { name: "a", items: vec![1, 2] },
+        Group { name: "b", items: vec![] },
+        Group { name: "c", items: vec![3] },
+    ]
+}
+
+#[test]
+fn borrowed_items() {
+    let groups = groups();
+    let items = groups.iter().flat_map(pluck!(.items.iter())).collect::<Vec<&u32>>();
+    assert_eq!(items, &[&1, &2, &3]);
+}
+
+#[test]
+fn borrowed_items_outlive_iteration() {
+    let groups = groups();
+    let mut items = groups.iter().flat_map(pluck!(.items.iter()));
+    let first = items.next();
+    let second = items.next();
+    drop(items);
+    assert_eq!((first, second), (Some(&1), Some(&2)));
+}
+
+#[test]
+fn nested_vecs() {
+    let nested = [vec![vec![1, 2], vec![3]], vec![vec![4]]];
+    let flat = nested
+        .iter()
+        .flat_map(pluck!(.iter()))
+        .flat_map(pluck!(.iter()))
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(flat, &[1, 2, 3, 4]);
+}
+
+#[test]
+fn skipping_empty_groups() {
+    let groups = groups();
+    let names = groups.iter().filter(pluck!(!.items.is_empty())).map(pluck!(.name));
+    let items = groups.iter().filter(pluck!(!.items.is_empty())).flat_map(pluck!(.items.iter()));
+    assert!(names.eq(["a", "c"]));
+    assert!(items.eq(&[1, 2, 3]));
+}
+
+#[test]
+fn owned_items() {
+    let items = groups().into_iter().flat_map(pluck!(.items)).collect::<Vec<u32>>();
+    assert_eq!(items, &[1, 2, 3]);
+}
+
+#[test]
+fn mutable_items() {
+    let mut groups = groups();
+    groups.iter_mut().flat_map(pluck!(.items.iter_mut())).for_each(|item| *item *= 10);
+
+    let items = groups.iter().flat_map(pluck!(.items.iter())).copied().collect::<Vec<_>>();
+    assert_eq!(items, &[10, 20, 30]);
+}
+
+struct Message {
+    text: String,
+}
+
+fn messages() -> Vec<Message> {
+    vec![
+        Message { text: "hi there".to_string() },
+        Message { text: String::new() },
+        Message { text: "yo".to_string() },
+    ]
+}
+
+#[test]
+fn string_chars() {
+    let messages = messages();
+    let chars = messages.iter().flat_map(pluck!(.text.chars())).collect::<String>();
+    assert_eq!(chars, "hi thereyo");
+}
+
+#[test]
+fn string_bytes() {
+    let messages = messages();
+    let bytes = messages.iter().flat_map(pluck!(.text.bytes())).collect::<Vec<u8>>();
+    assert_eq!(bytes, b"hi thereyo");
+}
+
+#[test]
+fn string_words_borrow_messages() {
+    let messages = messages();
+    let words = messages.iter().flat_map(pluck!(.text.split_whitespace())).collect::<Vec<&str>>();
+    assert_eq!(words, &["hi", "there", "yo"]);
+
+    let lens = messages.iter().flat_map(pluck!(.text.split_whitespace())).map(str::len);
+    assert!(lens.eq([2, 5, 2]));
+}
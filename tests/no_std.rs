@@ -0,0 +1,79 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use pluck::*;
+
+struct Person {
+    name: &'static str,
+    age: u32,
+    parent: Option<&'static Person>,
+}
+
+static ALICE: Person = Person { name: "Alice", age: 30, parent: None };
+static PEOPLE: [Person; 2] = [
+    Person { name: "Alice", age: 30, parent: None },
+    Person { name: "Bob", age: 5, parent: Some(&ALICE) },
+];
+
+#[test]
+fn property() {
+    let mut names = PEOPLE.iter().map(pluck!(.name));
+    assert_eq!(names.next(), Some("Alice"));
+    assert_eq!(names.next(), Some("Bob"));
+    assert_eq!(names.next(), None);
+}
+
+#[test]
+fn reference_and_copy() {
+    let total = PEOPLE.iter().map(pluck!(copy .age)).sum::<u32>();
+    assert_eq!(total, 35);
+
+    let ages = PEOPLE.iter().map(pluck!(&.age));
+    assert!(ages.eq([&30, &5]));
+}
+
+#[test]
+fn optional_chain() {
+    let parents = PEOPLE.iter().map(pluck!(.parent?.name));
+    assert!(parents.eq([None, Some("Alice")]));
+}
+
+#[test]
+fn index_and_deref() {
+    let list = [[&0], [&1], [&2]];
+    let derefed = list.iter().map(pluck!(*[0]));
+    assert!(derefed.eq([0, 1, 2]));
+}
+
+#[test]
+fn conversion() {
+    let list = [(1u8, 'a'), (2u8, 'b')];
+    let total = list.iter().map(pluck!(.0 into u64)).sum::<u64>();
+    assert_eq!(total, 3);
+
+    let floats = list.iter().map(pluck!(.0 as f32));
+    assert!(floats.eq([1.0, 2.0]));
+}
+
+#[test]
+fn tuple() {
+    let list = [(1u8, 'a'), (2u8, 'b')];
+    let swapped = list.iter().map(pluck!(.1, clone .0));
+    assert!(swapped.eq([('a', 1), ('b', 2)]));
+}
+
+#[test]
+fn aggregate() {
+    assert_eq!(pluck_sum!(&PEOPLE, .age), 35);
+    assert_eq!(pluck_product!(&PEOPLE, .age), 150);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec() {
+    let list = [(1u8, 2u8), (3u8, 4u8)];
+    let vecs = list.iter().map(pluck!(vec![.1, .0]));
+    assert!(vecs.eq([alloc::vec![2, 1], alloc::vec![4, 3]]));
+}
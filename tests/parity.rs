@@ -0,0 +1,15 @@
+//! Cases shared by the `macro_rules!` and procedural implementations of
+//! `pluck!`. Run with `--features proc-macro` to cover both.
+
+mod declarative {
+    use pluck::pluck;
+
+    include!("parity/cases.rs");
+}
+
+#[cfg(feature = "proc-macro")]
+mod parsed {
+    use pluck::parsed::pluck;
+
+    include!("parity/cases.rs");
+}
@@ -0,0 +1,115 @@
+struct Person {
+    name: String,
+    age: u32,
+    parent: Option<Box<Person>>,
+}
+
+fn people() -> Vec<Person> {
+    let alice = Person { name: "Alice".to_string(), age: 30, parent: None };
+    let bob = Person { name: "Bob".to_string(), age: 5, parent: Some(Box::new(alice)) };
+    vec![Person { name: "Alice".to_string(), age: 30, parent: None }, bob]
+}
+
+#[test]
+fn field() {
+    let ages = people().iter().map(pluck!(.age)).collect::<Vec<_>>();
+    assert_eq!(ages, &[30, 5]);
+}
+
+#[test]
+fn nested_tuple_index() {
+    let list = [((0, "a"), 'x'), ((1, "b"), 'y')];
+    let nested = list.iter().map(pluck!(.0.1)).collect::<Vec<_>>();
+    assert_eq!(nested, &["a", "b"]);
+}
+
+#[test]
+fn by_reference() {
+    let people = people();
+    let names = people.iter().map(pluck!(&.name)).collect::<Vec<&String>>();
+    assert_eq!(names, &["Alice", "Bob"]);
+
+    let names = people.iter().map(pluck!(&*.name)).collect::<Vec<&str>>();
+    assert_eq!(names, &["Alice", "Bob"]);
+}
+
+#[test]
+fn mutable_reference() {
+    let mut people = people();
+    people.iter_mut().map(pluck!(&mut .age)).for_each(|age| *age += 1);
+    assert_eq!(people.iter().map(pluck!(.age)).collect::<Vec<_>>(), &[31, 6]);
+}
+
+#[test]
+fn clone_and_copy() {
+    let people = people();
+    let names = people.iter().map(pluck!(clone .name)).collect::<Vec<String>>();
+    assert_eq!(names, &["Alice", "Bob"]);
+
+    let ages = people.iter().map(pluck!(copy .age)).collect::<Vec<u32>>();
+    assert_eq!(ages, &[30, 5]);
+}
+
+#[test]
+fn negation() {
+    let list = [(true,), (false,)];
+    let negated = list.iter().map(pluck!(!.0)).collect::<Vec<_>>();
+    assert_eq!(negated, &[false, true]);
+}
+
+#[test]
+fn method_call() {
+    let people = people();
+    let lens = people.iter().map(pluck!(.name.len())).collect::<Vec<_>>();
+    assert_eq!(lens, &[5, 3]);
+
+    let clamped = people.iter().map(pluck!(.age.min(10))).collect::<Vec<_>>();
+    assert_eq!(clamped, &[10, 5]);
+}
+
+#[test]
+fn turbofish() {
+    let list = [("1",), ("2",)];
+    let parsed = list.iter().map(pluck!(.0.parse::<i32>().unwrap())).collect::<Vec<_>>();
+    assert_eq!(parsed, &[1, 2]);
+}
+
+#[test]
+fn index() {
+    let list = [vec![1, 2], vec![3, 4]];
+    let second = list.iter().map(pluck!([1])).collect::<Vec<_>>();
+    assert_eq!(second, &[2, 4]);
+}
+
+#[test]
+fn optional_chain() {
+    let people = people();
+    let parents = people.iter().map(pluck!(.parent?.age)).collect::<Vec<_>>();
+    assert_eq!(parents, &[None, Some(30)]);
+}
+
+#[test]
+fn tuple() {
+    let people = people();
+    let pairs = people.iter().map(pluck!(.age, &.name)).collect::<Vec<(u32, &String)>>();
+    assert_eq!(pairs, &[(30, &"Alice".to_string()), (5, &"Bob".to_string())]);
+}
+
+#[test]
+fn moved() {
+    let list = [(1,), (2,)];
+    let moved = list.iter().map(pluck!(move .0)).collect::<Vec<_>>();
+    assert_eq!(moved, &[1, 2]);
+}
+
+#[test]
+fn outer_variable_named_value() {
+    let list = [[0, 1], [2, 3]];
+    let value = 1;
+    let second = list.iter().map(pluck!([value])).collect::<Vec<_>>();
+    assert_eq!(second, &[1, 3]);
+
+    let value = 0;
+    let first = list.iter().map(pluck!(.get(value))).collect::<Vec<_>>();
+    assert_eq!(first, &[Some(&0), Some(&2)]);
+}
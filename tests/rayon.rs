@@ -0,0 +1,54 @@
+//! Lambdas in `rayon` parallel iterators, which require `Send + Sync`.
+
+use pluck::*;
+use rayon::prelude::*;
+
+struct Person {
+    name: String,
+    age: u32,
+}
+
+fn people() -> Vec<Person> {
+    (0..100).map(|i| Person { name: format!("person {}", i), age: i }).collect()
+}
+
+fn assert_send_sync<T, R, F: Fn(T) -> R + Send + Sync>(f: F) -> F {
+    f
+}
+
+#[test]
+fn lambdas_are_send_and_sync() {
+    let people = people();
+    let name = assert_send_sync::<&Person, _, _>(pluck!(&.name));
+    let age = assert_send_sync::<&Person, _, _>(pluck!(.age));
+    assert_eq!((name(&people[1]).as_str(), age(&people[1])), ("person 1", 1));
+}
+
+#[test]
+fn par_iter_map() {
+    let people = people();
+
+    let names = people.par_iter().map(pluck!(&*.name)).collect::<Vec<&str>>();
+    assert_eq!(names.len(), 100);
+    assert_eq!(names[42], "person 42");
+
+    let total = people.par_iter().map(pluck!(.age)).sum::<u32>();
+    assert_eq!(total, (0..100).sum());
+}
+
+#[test]
+fn par_iter_mut_and_into_par_iter() {
+    let mut people = people();
+    people.par_iter_mut().map(pluck!(&mut .age)).for_each(|age| *age *= 2);
+
+    let ages = people.into_par_iter().map(pluck!(.age)).collect::<Vec<_>>();
+    assert_eq!(ages[..3], [0, 2, 4]);
+}
+
+#[test]
+fn par_sort_by_key() {
+    let mut people = people();
+    people.reverse();
+    people.par_sort_by_key(sort_key!(.age));
+    assert!(people.iter().map(pluck!(.age)).eq(0..100));
+}
@@ -0,0 +1,138 @@
+//! What each access form yields for borrowed and owned arguments.
+
+use pluck::*;
+
+type Pair = (i32, String);
+
+fn apply<T, R>(value: T, f: impl FnOnce(T) -> R) -> R {
+    f(value)
+}
+
+fn pair() -> Pair {
+    (1, "a".to_string())
+}
+
+#[test]
+fn borrowed_argument() {
+    let value = &pair();
+
+    let _: i32 = apply(value, pluck!(.0));
+    let _: &i32 = apply(value, pluck!(&.0));
+    let _: i32 = apply(value, pluck!(copy .0));
+    let _: i32 = apply(value, pluck!(clone .0));
+
+    let _: &String = apply(value, pluck!(&.1));
+    let _: String = apply(value, pluck!(clone .1));
+    let _: &str = apply(value, pluck!(.1.as_str()));
+    let _: usize = apply(value, pluck!(.1.len()));
+
+    let _: (i32, &String) = apply(value, pluck!(.0, &.1));
+    let _: i64 = apply(value, pluck!(.0 into i64));
+    let _: u8 = apply(value, pluck!(.0 as u8));
+}
+
+#[test]
+fn mutably_borrowed_argument() {
+    let value = &mut pair();
+
+    let _: i32 = apply(&mut *value, pluck!(.0));
+    let _: &i32 = apply(&mut *value, pluck!(&.0));
+    let _: &mut i32 = apply(&mut *value, pluck!(&mut .0));
+    let _: &mut String = apply(&mut *value, pluck!(&mut .1));
+    let _: &mut str = apply(value, pluck!(.1.as_mut_str()));
+}
+
+#[test]
+fn owned_argument() {
+    let _: i32 = apply(pair(), pluck!(.0));
+    let _: String = apply(pair(), pluck!(.1));
+    let _: (i32, String) = apply(pair(), pluck!(.0, .1));
+    let _: Vec<u8> = apply(pair(), pluck!(.1.into_bytes()));
+}
+
+#[test]
+fn nested_references() {
+    let value = &&pair();
+
+    let _: i32 = apply(value, pluck!(.0));
+    let _: &String = apply(value, pluck!(&.1));
+    let _: &Pair = apply(value, pluck!(*));
+    let _: Pair = apply(value, pluck!(clone **));
+}
+
+#[test]
+fn optional_argument() {
+    let value = &Some(pair());
+
+    let _: Option<i32> = apply(value, pluck!(?.0));
+    let _: Option<&String> = apply(value, pluck!(?&.1));
+
+    let field = &(Some(1), Some("a".to_string()));
+    let _: Option<i32> = apply(field, pluck!(.0?));
+    let _: Option<&String> = apply(field, pluck!(flatten .1));
+    let _: i32 = apply(field, pluck!(.0 unwrap));
+}
+
+fn assert_copy<T, R, F: Fn(T) -> R + Copy>(f: F) -> F {
+    f
+}
+
+fn assert_clone<T, R, F: Fn(T) -> R + Clone>(f: F) -> F {
+    f
+}
+
+#[test]
+fn lambdas_without_captures_are_copy() {
+    let value = &pair();
+
+    let first = assert_copy::<&Pair, _, _>(pluck!(.0));
+    let again = first;
+    let _: i32 = apply(value, first);
+    let _: i32 = apply(value, again);
+
+    let _: &String = apply(value, assert_copy::<&Pair, _, _>(pluck!(&.1)));
+    let _: String = apply(value, assert_copy::<&Pair, _, _>(pluck!(clone .1)));
+    let _: (i32, usize) = apply(value, assert_copy::<&Pair, _, _>(pluck!(.0, .1.len())));
+
+    let value = &Some(pair());
+    let _: Option<i32> = apply(value, assert_copy::<&Option<Pair>, _, _>(pluck!(?.0)));
+}
+
+#[test]
+fn lambdas_with_captures_follow_their_captures() {
+    use std::collections::HashMap;
+
+    let position = 1;
+    let index = assert_copy::<&Vec<i32>, _, _>(pluck!(move [position]));
+    assert_eq!(index(&vec![1, 2]), 2);
+
+    let key = "a".to_string();
+    let lookup = assert_clone::<&HashMap<String, i32>, _, _>(pluck!(move get[&key]));
+    let map = HashMap::from([("a".to_string(), 1)]);
+    assert_eq!(lookup.clone()(&map), Some(&1));
+    assert_eq!(lookup(&map), Some(&1));
+}
+
+pluck_fn!(fn first(&Pair) -> i32 = .0);
+
+#[test]
+fn named_functions_are_copy() {
+    let value = &pair();
+
+    let f = assert_copy(first);
+    let _: i32 = apply(value, f);
+    let _: i32 = apply(value, first);
+}
+
+#[test]
+fn let_bound_lambdas_are_reusable() {
+    let list = [pair(), pair()];
+
+    let first = assert_copy::<&Pair, _, _>(pluck!(.0));
+    let cloned = assert_clone::<&Pair, _, _>(first);
+    let sum = list.iter().map(first).sum::<i32>();
+    let max = list.iter().map(cloned).max();
+    let again = list.iter().map(first).collect::<Vec<_>>();
+    assert_eq!((sum, max), (again.iter().sum(), again.iter().copied().max()));
+    assert_eq!(first(&list[0]), cloned(&list[1]));
+}